@@ -0,0 +1,258 @@
+use std::fs::File;
+use std::io::{Read, Write};
+
+use regex::Regex;
+
+use rules::LogItem;
+
+/// Default name of the baseline file.
+pub const BASELINE_FILE: &str = ".latexerr-baseline.json";
+
+/// Log item recorded in the baseline. Neither the location nor line and page numbers in the
+/// fields are a part of the entry so that the baseline stays valid when the source file is edited.
+#[derive(Clone, PartialEq)]
+pub struct Entry {
+    file: String,
+    rule: String,
+    fingerprint: String,
+}
+
+impl Entry {
+    pub fn new(file: &str, log_item: &LogItem) -> Self {
+        Self {
+            file: file.to_owned(),
+            rule: log_item.item_type.get_id().to_owned(),
+            fingerprint: fingerprint(log_item),
+        }
+    }
+}
+
+/// Set of known log items, which are not reported. Each entry hides only one log item, so a new
+/// occurrence of an already known item is still reported.
+pub struct Baseline {
+    entries: Vec<Entry>,
+}
+
+impl Baseline {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn load(filename: &str) -> Result<Self, String> {
+        let mut content = String::new();
+        File::open(filename)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .map_err(|_| format!("Cannot read {}", filename))?;
+
+        let entry = r#"\{\s*"file":\s*"((?:[^"\\]|\\.)*)",\s*"rule":\s*"([^"]*)",\s*"fingerprint":\s*"([^"]*)"\s*\}"#;
+        let pattern = Regex::new(entry).unwrap();
+
+        // the whole file must be a list of entries, so that a truncated or edited baseline is not
+        // silently ignored
+        let list = format!(r"\A\s*\[\s*(?:{0}(?:\s*,\s*{0})*\s*)?\]\s*\z", entry);
+        if !Regex::new(&list).unwrap().is_match(&content) {
+            return Err(format!("Invalid baseline file {}", filename));
+        }
+
+        let entries = pattern
+            .captures_iter(&content)
+            .map(|captures| Entry {
+                file: unescape(&captures[1]),
+                rule: captures[2].to_owned(),
+                fingerprint: captures[3].to_owned(),
+            })
+            .collect();
+
+        Ok(Self { entries })
+    }
+
+    pub fn save(&self, filename: &str) -> Result<(), String> {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "  {{\"file\": \"{}\", \"rule\": \"{}\", \"fingerprint\": \"{}\"}}",
+                    escape(&entry.file),
+                    entry.rule,
+                    entry.fingerprint
+                )
+            })
+            .collect::<Vec<String>>();
+
+        let content = if entries.is_empty() {
+            String::from("[]\n")
+        } else {
+            format!("[\n{}\n]\n", entries.join(",\n"))
+        };
+
+        File::create(filename)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+            .map_err(|_| format!("Cannot write {}", filename))
+    }
+
+    pub fn add(&mut self, file: &str, log_item: &LogItem) {
+        self.entries.push(Entry::new(file, log_item));
+    }
+
+    /// Removes log items of a source file which are in the baseline.
+    pub fn apply<'a>(&self, file: &str, log_items: Vec<LogItem<'a>>) -> Vec<LogItem<'a>> {
+        let mut known = self
+            .entries
+            .iter()
+            .filter(|entry| entry.file == file)
+            .collect::<Vec<&Entry>>();
+
+        log_items
+            .into_iter()
+            .filter(|log_item| {
+                let entry = Entry::new(file, log_item);
+
+                match known.iter().position(|known| **known == entry) {
+                    Some(index) => {
+                        known.remove(index);
+                        false
+                    }
+                    None => true,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Computes a fingerprint of a log item from its fields, which does not depend on the location
+/// of the item nor on other line and page numbers.
+pub fn fingerprint(log_item: &LogItem) -> String {
+    // 64-bit FNV-1a, which is stable across platforms and compiler versions
+    let hash = log_item
+        .item_type
+        .get_key()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+
+    format!("{:016x}", hash)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("\\\"", "\"").replace("\\\\", "\\")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+
+    use rules::{Location, LogItemType, LogItemTypeLevel};
+
+    fn log_item(item_type: LogItemType, line: usize) -> LogItem {
+        LogItem {
+            item_type,
+            location: Location::Line(line),
+            level: LogItemTypeLevel::Warning,
+        }
+    }
+
+    #[test]
+    fn shifted_items_are_known() {
+        let filename = env::temp_dir().join("latexerr-baseline-test.json");
+        let filename = filename.to_str().unwrap();
+
+        let mut recorded = Baseline::new();
+        recorded.add(
+            "main.tex",
+            &log_item(LogItemType::UndefinedCitation("foo", 1), 4),
+        );
+        recorded.add(
+            "main.tex",
+            &log_item(LogItemType::UndefinedControlSequence("\\foo"), 6),
+        );
+        recorded.add("main.tex", &log_item(LogItemType::MarginparMoved(2), 9));
+        recorded.save(filename).unwrap();
+
+        // lines were added at the beginning of the source file, which moved the items to next pages
+        let log_items = vec![
+            log_item(LogItemType::UndefinedCitation("foo", 2), 24),
+            log_item(LogItemType::UndefinedControlSequence("\\foo"), 26),
+            log_item(LogItemType::UndefinedControlSequence("\\foo"), 27),
+            log_item(LogItemType::MarginparMoved(3), 29),
+            log_item(LogItemType::UndefinedCitation("bar", 3), 31),
+        ];
+
+        let baseline = Baseline::load(filename).unwrap();
+        let log_items = baseline.apply("main.tex", log_items);
+
+        assert_eq!(
+            log_items
+                .iter()
+                .map(|log_item| &log_item.location)
+                .collect::<Vec<&Location>>(),
+            vec![&Location::Line(27), &Location::Line(31)]
+        );
+
+        // items of other files are not hidden
+        let log_items = vec![log_item(LogItemType::UndefinedCitation("foo", 1), 4)];
+        assert_eq!(baseline.apply("chapter.tex", log_items).len(), 1);
+    }
+
+    #[test]
+    fn fingerprints_are_stable() {
+        // fingerprints are stored in baseline files, so they must not change between versions
+        assert_eq!(
+            fingerprint(&log_item(LogItemType::UndefinedCitation("foo", 1), 4)),
+            "e09c53c9e6e73f08"
+        );
+        assert_eq!(
+            fingerprint(&log_item(
+                LogItemType::UnclosedGroup("simple", 12, "\\end{document}"),
+                20
+            )),
+            "576645ef67583513"
+        );
+    }
+
+    #[test]
+    fn malformed_files_are_rejected() {
+        let filename = env::temp_dir().join("latexerr-baseline-malformed-test.json");
+        let filename = filename.to_str().unwrap();
+
+        let entry = r#"{"file": "main.tex", "rule": "LX0060", "fingerprint": "e09c53c9e6e73f08"}"#;
+        for content in &[
+            format!("[\n  {},\n  {}\n]\n", entry, entry),
+            String::from("[]\n"),
+        ] {
+            File::create(filename)
+                .unwrap()
+                .write_all(content.as_bytes())
+                .unwrap();
+            assert!(Baseline::load(filename).is_ok(), "{} is rejected", content);
+        }
+
+        for content in &[
+            // truncated
+            format!("[\n  {},\n  {}", entry, &entry[..30]),
+            format!("[\n  {},\n", entry),
+            // unknown field
+            String::from(r#"[{"file": "main.tex", "rule": "LX0060", "hash": "e09c53c9e6e73f08"}]"#),
+            String::from("foo"),
+            String::new(),
+        ] {
+            File::create(filename)
+                .unwrap()
+                .write_all(content.as_bytes())
+                .unwrap();
+            assert_eq!(
+                Baseline::load(filename).err(),
+                Some(format!("Invalid baseline file {}", filename))
+            );
+        }
+    }
+}
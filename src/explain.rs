@@ -0,0 +1,63 @@
+use rules::resolve_rule;
+
+/// Returns the explanation of a rule referred to the same way as in severity configuration, for
+/// example by its identifier (`LX0001`) or its name (`undefined-control-sequence`).
+pub fn explain(query: &str) -> Result<String, String> {
+    let rule = resolve_rule(query)?;
+    let mut output = format!("{} {}\n\n", rule.id, rule.name);
+
+    // code blocks are indented instead of fenced
+    let mut in_code = false;
+    for line in rule.explanation.lines() {
+        if line.starts_with("```") {
+            in_code = !in_code;
+        } else if in_code && !line.is_empty() {
+            output += &format!("    {}\n", line);
+        } else {
+            output += &format!("{}\n", line);
+        }
+    }
+
+    Ok(output.trim_end().to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rules::RULES;
+
+    #[test]
+    fn rules_have_sequential_ids_and_unique_names() {
+        for (index, rule) in RULES.iter().enumerate() {
+            assert_eq!(rule.id, format!("LX{:04}", index + 1));
+            assert_eq!(
+                RULES.iter().filter(|other| other.name == rule.name).count(),
+                1,
+                "Rule name {} is not unique",
+                rule.name
+            );
+        }
+    }
+
+    #[test]
+    fn every_rule_has_explanation() {
+        for rule in RULES {
+            let explanation = explain(rule.id).unwrap();
+
+            assert!(
+                explanation.contains("Example log output:"),
+                "Rule {} has no example",
+                rule.id
+            );
+            assert_eq!(explain(rule.name), Ok(explanation));
+        }
+    }
+
+    #[test]
+    fn explain_accepts_prefixes() {
+        assert_eq!(explain("overfull"), explain("LX0006"));
+        assert_eq!(explain("foo"), Err(String::from("Unknown rule foo")));
+        assert!(explain("missing").is_err());
+    }
+}
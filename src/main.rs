@@ -5,7 +5,11 @@
 extern crate regex;
 extern crate yansi;
 
+mod baseline;
+mod explain;
 mod rules;
+mod severity;
+mod suppression;
 mod utils;
 
 use std::cmp::Ordering;
@@ -14,18 +18,23 @@ use std::env;
 use std::fs::File;
 use std::io::Read;
 use std::iter::FromIterator;
+use std::path::Path;
+use std::process;
 
 use regex::Regex;
 use yansi::Paint;
 
-use rules::{Location, LogItem};
+use baseline::{Baseline, Entry, BASELINE_FILE};
+use rules::{Location, LogItem, LogItemTypeLevel};
+use severity::{Severities, CONFIG_FILE};
+use suppression::Suppressions;
 
 fn get_file_block_indices(log: &str) -> Vec<(usize, usize, String)> {
     let mut open = Vec::new();
     let mut blocks = Vec::new();
 
     // find start and end indices of all blocks
-    for (index, character) in log.chars().enumerate() {
+    for (index, character) in log.char_indices() {
         match character {
             '(' => open.push(index),
             ')' => blocks.push((open.pop().unwrap(), index)),
@@ -88,11 +97,12 @@ fn process(log: &str) -> HashMap<String, Vec<LogItem>> {
         for rule in &rules {
             let regex = rule.get_regex();
 
+            let mut found = Vec::new();
             for (start, end) in &parts {
-                for found in rule.captures(regex.clone(), &log[*start..*end]) {
-                    log_items.push(rule.process(found));
-                }
+                found.append(&mut rule.captures(regex.clone(), &log[*start..*end]));
             }
+
+            log_items.append(&mut rule.process_all(found));
         }
 
         // add this block's indices to those which are already processed
@@ -128,45 +138,372 @@ fn process(log: &str) -> HashMap<String, Vec<LogItem>> {
         .collect()
 }
 
+/// Exit status when an error remains in a log.
+const EXIT_ERRORS: i32 = 1;
+
+/// Exit status when the arguments, the configuration or the baseline are invalid.
+const EXIT_USAGE: i32 = 2;
+
 fn main() {
-    let files = env::args()
-        .filter(|arg| arg.ends_with(".log"))
-        .collect::<Vec<String>>();
+    let mut files = Vec::new();
+    let mut severities = Severities::new();
+    let mut baseline = None;
+    let mut write_baseline = None;
+    let mut diff = false;
+
+    if let Err(error) = severities.load(CONFIG_FILE) {
+        eprintln!("{}", error);
+        process::exit(EXIT_USAGE);
+    }
+
+    // severities given on the command line take precedence over the configuration file
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--explain" {
+            match args.next() {
+                Some(rule) => match explain::explain(&rule) {
+                    Ok(explanation) => println!("{}", explanation),
+                    Err(error) => {
+                        eprintln!("{}", error);
+                        process::exit(EXIT_USAGE);
+                    }
+                },
+                None => {
+                    eprintln!("Missing value of --explain");
+                    process::exit(EXIT_USAGE);
+                }
+            }
+
+            return;
+        } else if arg == "--severity" {
+            match args.next() {
+                Some(entry) => if let Err(error) = severities.add(&entry) {
+                    eprintln!("{}", error);
+                    process::exit(EXIT_USAGE);
+                },
+                None => {
+                    eprintln!("Missing value of --severity");
+                    process::exit(EXIT_USAGE);
+                }
+            }
+        } else if let Some((option, path)) = parse_baseline_option(&arg) {
+            if path.is_empty() {
+                eprintln!("Missing value of {}", option);
+                process::exit(EXIT_USAGE);
+            }
+
+            if option == "--write-baseline" {
+                write_baseline = Some(path.to_owned());
+            } else {
+                match Baseline::load(path) {
+                    Ok(loaded) => baseline = Some(loaded),
+                    Err(error) => {
+                        eprintln!("{}", error);
+                        process::exit(EXIT_USAGE);
+                    }
+                }
+            }
+        } else if arg == "diff" && files.is_empty() {
+            diff = true;
+        } else if arg.ends_with(".log") {
+            files.push(arg);
+        }
+    }
+
+    let mut recorded = Baseline::new();
+    let mut status = 0;
 
     if files.is_empty() {
         eprintln!("No files were passed");
+        status = EXIT_USAGE;
+    } else if diff {
+        if baseline.is_some() || write_baseline.is_some() {
+            eprintln!("Baseline cannot be used with diff");
+            status = EXIT_USAGE;
+        } else if files.len() == 2 {
+            status = print_diff(&files[0], &files[1], &severities);
+        } else {
+            eprintln!("Two log files must be passed to diff");
+            status = EXIT_USAGE;
+        }
     } else {
         for filename in files {
-            match File::open(filename.clone()) {
-                Ok(mut file) => {
-                    let mut buffer = String::new();
-                    file.read_to_string(&mut buffer)
-                        .expect(&format!("Cannot read {}", filename));
-
-                    let items = process(&buffer);
-                    let count = items.len();
-
-                    // sort files by filename
-                    let mut items = items.into_iter().collect::<Vec<(String, Vec<LogItem>)>>();
-                    items.sort_unstable_by(|a, b| a.0.cmp(&b.0));
-
-                    // print all source files and corresponding items
-                    for (index, (filename, log_items)) in items.iter().enumerate() {
-                        println!("{} {}", Paint::cyan("File:"), filename);
-                        println!();
-
-                        for log_item in log_items {
-                            println!("{}", log_item);
-                        }
-
-                        // don't add new line after last file
-                        if index < count - 1 {
-                            println!();
-                        }
-                    }
+            let buffer = match read_log(&filename) {
+                Some(buffer) => buffer,
+                None => {
+                    eprintln!("Cannot read {}", filename);
+                    status = EXIT_USAGE;
+                    continue;
+                }
+            };
+
+            // report bibliography items in separate sections after the source ones, files without
+            // any items are not reported
+            let mut sections = Vec::new();
+            let mut bibliography = Vec::new();
+            for (filename, log_items) in collect(&buffer, &filename, &severities) {
+                for log_item in &log_items {
+                    recorded.add(&filename, log_item);
+                }
+
+                // hide known items
+                let log_items = match baseline {
+                    Some(ref baseline) => baseline.apply(&filename, log_items),
+                    None => log_items,
+                };
+
+                if status == 0 && log_items
+                    .iter()
+                    .any(|log_item| log_item.level == LogItemTypeLevel::Error)
+                {
+                    status = EXIT_ERRORS;
+                }
+
+                let (bibliography_items, source_items): (Vec<LogItem>, Vec<LogItem>) = log_items
+                    .into_iter()
+                    .partition(|log_item| log_item.item_type.is_bibliography());
+
+                if !bibliography_items.is_empty() {
+                    bibliography.push(("Bibliography:", filename.clone(), bibliography_items));
+                }
+
+                if !source_items.is_empty() {
+                    sections.push(("File:", filename, source_items));
+                }
+            }
+
+            sections.append(&mut bibliography);
+            print_sections(&sections);
+        }
+
+        if let Some(path) = write_baseline {
+            if let Err(error) = recorded.save(&path) {
+                eprintln!("{}", error);
+                status = EXIT_USAGE;
+            }
+        }
+    }
+
+    process::exit(status);
+}
+
+fn read_log(filename: &str) -> Option<String> {
+    let mut buffer = Vec::new();
+    File::open(filename)
+        .and_then(|mut file| file.read_to_end(&mut buffer))
+        .ok()?;
+
+    // log can contain invalid UTF-8 when the source file is in a different encoding
+    Some(String::from_utf8_lossy(&buffer).into_owned())
+}
+
+/// Processes a log and returns items reported for each source file, sorted by the file name, with
+/// suppressions and severities applied.
+fn collect<'a>(
+    log: &'a str,
+    log_filename: &str,
+    severities: &Severities,
+) -> Vec<(String, Vec<LogItem<'a>>)> {
+    let mut items = process(log).into_iter().collect::<Vec<(String, Vec<LogItem>)>>();
+    items.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    // source files are relative to the log file
+    let directory = Path::new(log_filename).parent().unwrap_or(Path::new(""));
+
+    items
+        .into_iter()
+        .map(|(filename, log_items)| {
+            let suppressions = Suppressions::load(&directory.join(&filename));
+            let log_items = severities.apply(suppressions.apply(log_items));
+
+            (filename, log_items)
+        })
+        .collect()
+}
+
+fn print_sections(sections: &[(&str, String, Vec<LogItem>)]) {
+    let count = sections.len();
+
+    // print all source files and corresponding items
+    for (index, (header, filename, log_items)) in sections.iter().enumerate() {
+        println!("{} {}", Paint::cyan(header), filename);
+        println!();
+
+        for log_item in log_items {
+            println!("{}", log_item);
+        }
+
+        // don't add new line after last file
+        if index < count - 1 {
+            println!();
+        }
+    }
+}
+
+/// Compares items of two logs of the same document and returns the status of the comparison.
+fn print_diff(old_filename: &str, new_filename: &str, severities: &Severities) -> i32 {
+    let (old_buffer, new_buffer) = match (read_log(old_filename), read_log(new_filename)) {
+        (Some(old_buffer), Some(new_buffer)) => (old_buffer, new_buffer),
+        (None, _) => {
+            eprintln!("Cannot read {}", old_filename);
+            return EXIT_USAGE;
+        }
+        (_, None) => {
+            eprintln!("Cannot read {}", new_filename);
+            return EXIT_USAGE;
+        }
+    };
+
+    let sections = diff(
+        collect(&old_buffer, old_filename, severities),
+        collect(&new_buffer, new_filename, severities),
+    );
+    print_sections(&sections);
+
+    let new_errors = sections
+        .iter()
+        .filter(|(header, _, _)| *header == "New:")
+        .flat_map(|(_, _, log_items)| log_items)
+        .any(|log_item| log_item.level == LogItemTypeLevel::Error);
+
+    if new_errors {
+        EXIT_ERRORS
+    } else {
+        0
+    }
+}
+
+/// Parses the `--baseline` and `--write-baseline` options into the option and the file name, which
+/// is given after `=` and defaults to `BASELINE_FILE`.
+fn parse_baseline_option(arg: &str) -> Option<(&str, &str)> {
+    let (option, path) = arg.split_once('=').unwrap_or((arg, BASELINE_FILE));
+
+    if option == "--baseline" || option == "--write-baseline" {
+        Some((option, path))
+    } else {
+        None
+    }
+}
+
+/// Splits items of two logs into new, resolved and persisting ones. Items are matched the same way
+/// as in the baseline, so that shifted lines and pages do not make a difference.
+fn diff<'a>(
+    mut old_items: Vec<(String, Vec<LogItem<'a>>)>,
+    new_items: Vec<(String, Vec<LogItem<'a>>)>,
+) -> Vec<(&'static str, String, Vec<LogItem<'a>>)> {
+    let mut added = Vec::new();
+    let mut persisting = Vec::new();
+
+    for (filename, log_items) in new_items {
+        let mut old = match old_items.iter().position(|(old, _)| *old == filename) {
+            Some(index) => old_items.remove(index).1,
+            None => Vec::new(),
+        };
+
+        let mut new = Vec::new();
+        let mut same = Vec::new();
+        for log_item in log_items {
+            let entry = Entry::new(&filename, &log_item);
+
+            match old
+                .iter()
+                .position(|old_item| Entry::new(&filename, old_item) == entry)
+            {
+                Some(index) => {
+                    old.remove(index);
+                    same.push(log_item);
                 }
-                Err(_) => eprintln!("Cannot read {}", filename),
+                None => new.push(log_item),
             }
         }
+
+        if !new.is_empty() {
+            added.push(("New:", filename.clone(), new));
+        }
+
+        if !same.is_empty() {
+            persisting.push(("Persisting:", filename.clone(), same));
+        }
+
+        // what is left was not found in the new log
+        old_items.push((filename, old));
+    }
+
+    let mut resolved = old_items
+        .into_iter()
+        .filter(|(_, log_items)| !log_items.is_empty())
+        .map(|(filename, log_items)| ("Resolved:", filename, log_items))
+        .collect::<Vec<(&str, String, Vec<LogItem>)>>();
+    resolved.sort_unstable_by(|a, b| a.1.cmp(&b.1));
+
+    let mut sections = added;
+    sections.append(&mut resolved);
+    sections.append(&mut persisting);
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moved_items_are_persisting() {
+        let old_log = "(./main.tex\n\
+                       LaTeX Warning: Citation `foo' on page 1 undefined on input line 4.\n\
+                       LaTeX Warning: Citation `bar' on page 1 undefined on input line 5.\n\
+                       )\n";
+        let new_log = "(./main.tex\n\
+                       LaTeX Warning: Citation `foo' on page 2 undefined on input line 40.\n\
+                       LaTeX Warning: Citation `baz' on page 2 undefined on input line 41.\n\
+                       )\n";
+
+        let severities = Severities::new();
+        let sections = diff(
+            collect(old_log, "old.log", &severities),
+            collect(new_log, "new.log", &severities),
+        );
+
+        let sections = sections
+            .iter()
+            .map(|(header, filename, log_items)| {
+                let lines = log_items
+                    .iter()
+                    .map(|log_item| match log_item.location {
+                        Location::Line(line) => line,
+                        _ => 0,
+                    })
+                    .collect::<Vec<usize>>();
+
+                (*header, filename.as_str(), lines)
+            })
+            .collect::<Vec<(&str, &str, Vec<usize>)>>();
+
+        assert_eq!(
+            sections,
+            vec![
+                ("New:", "main.tex", vec![41]),
+                ("Resolved:", "main.tex", vec![5]),
+                ("Persisting:", "main.tex", vec![40]),
+            ]
+        );
+    }
+
+    #[test]
+    fn baseline_file_name_follows_equals_sign() {
+        assert_eq!(
+            parse_baseline_option("--baseline"),
+            Some(("--baseline", BASELINE_FILE))
+        );
+        assert_eq!(
+            parse_baseline_option("--write-baseline=known.json"),
+            Some(("--write-baseline", "known.json"))
+        );
+        assert_eq!(
+            parse_baseline_option("--baseline="),
+            Some(("--baseline", ""))
+        );
+        // a separate argument is never taken as the file name
+        assert_eq!(parse_baseline_option("known.json"), None);
+        assert_eq!(parse_baseline_option("--baselines"), None);
     }
 }
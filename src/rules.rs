@@ -4,561 +4,5864 @@ use std::str::FromStr;
 use regex::{Captures, Regex};
 use yansi::Paint;
 
-use utils::PatternBuilder;
+use utils::{edit_distance, wrapped_captures, PatternBuilder};
 
-#[derive(PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub enum Location {
     Line(usize),
     End,
     None,
 }
 
-pub enum LogItemTypeLevel {
-    Error,
-    Warning,
+/// Kind of a file which could not be found, determined from its extension or from the command
+/// which requested it.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    Class,
+    Package,
+    Graphic,
+    Input,
+    Bibliography,
+    Font,
+    Other,
 }
 
-impl fmt::Display for LogItemTypeLevel {
+impl FileKind {
+    fn classify(filename: &str, input: &str) -> Self {
+        let extension = match filename.rfind('.') {
+            Some(index) => &filename[index + 1..],
+            None => "",
+        };
+
+        match extension {
+            "cls" => FileKind::Class,
+            "sty" => FileKind::Package,
+            "png" | "jpg" | "jpeg" | "pdf" | "eps" | "ps" | "svg" | "jbig2" => FileKind::Graphic,
+            "tex" => FileKind::Input,
+            "bbl" | "bib" | "bst" | "bcf" => FileKind::Bibliography,
+            "tfm" | "fd" | "vf" | "pfb" | "otf" | "ttf" | "enc" | "map" => FileKind::Font,
+            _ => if input.contains("\\includegraphics") {
+                FileKind::Graphic
+            } else if input.contains("\\documentclass") {
+                FileKind::Class
+            } else if input.contains("\\usepackage") || input.contains("\\RequirePackage") {
+                FileKind::Package
+            } else if input.contains("\\input") || input.contains("\\include") {
+                FileKind::Input
+            } else {
+                FileKind::Other
+            },
+        }
+    }
+}
+
+/// Area of an engine-level message (from pdfTeX, XeTeX's xdvipdfmx or LuaTeX), determined from
+/// the text of the message.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum EngineMessageKind {
+    Inclusion,
+    Link,
+    Font,
+    Backend,
+}
+
+impl EngineMessageKind {
+    fn classify(message: &str) -> Self {
+        let message = message.to_lowercase();
+
+        if message.contains("inclusion") || message.contains("image") || message.contains("(file ")
+        {
+            EngineMessageKind::Inclusion
+        } else if message.contains("link") || message.contains("dest") || message.contains("annot")
+        {
+            EngineMessageKind::Link
+        } else if message.contains("font") || message.contains("glyph") || message.contains("tfm") {
+            EngineMessageKind::Font
+        } else {
+            EngineMessageKind::Backend
+        }
+    }
+}
+
+impl fmt::Display for EngineMessageKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            LogItemTypeLevel::Error => write!(f, "{}", Paint::red("Error")),
-            LogItemTypeLevel::Warning => write!(f, "{}", Paint::yellow("Warning")),
+            EngineMessageKind::Inclusion => write!(f, "image inclusion"),
+            EngineMessageKind::Link => write!(f, "links"),
+            EngineMessageKind::Font => write!(f, "fonts"),
+            EngineMessageKind::Backend => write!(f, "PDF output"),
         }
     }
 }
 
-#[derive(PartialEq, Eq, Hash)]
-pub enum LogItemType<'a> {
-    /// When there is used a control sequence which is undefined.
-    ///
-    /// Example latex source:
-    /// ```latex
-    /// \documentclass{article}
-    ///
-    /// \begin{document}
-    /// \foo
-    /// \end{document}
-    /// ```
-    ///
-    /// Example log output:
-    /// ```txt
-    /// ! Undefined control sequence.
-    /// l.4 \foo
-    /// ```
-    UndefinedControlSequence(&'a str),
+/// Rule which finds log items: its stable identifier, its name used in the configuration and its
+/// explanation with examples printed by `--explain`.
+pub struct RuleInfo {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub explanation: &'static str,
+}
 
-    /// When there is missing starting brace for a command.
-    ///
-    /// Example latex source:
-    /// ```latex
-    /// \documentclass{article}
-    ///
-    /// \begin{document}
-    /// \date April 2018}
-    /// \end{document}
-    /// ```
-    ///
-    /// Example log output:
-    /// ```txt
-    /// ! Too many }'s.
-    /// l.4 \date April 2018}
-    /// ```
-    TooManyEndingBraces(&'a str),
+/// All rules ordered by their identifiers. New rules are always added to the end so the
+/// identifiers never change.
+pub const RULES: &[&RuleInfo] = &[
+    &UNDEFINED_CONTROL_SEQUENCE,
+    &TOO_MANY_ENDING_BRACES,
+    &NOT_IN_MATH_MODE,
+    &RUNAWAY_ARGUMENT,
+    &UNDERFULL_HBOX,
+    &OVERFULL_HBOX,
+    &MISSING_FILE,
+    &UNKNOWN_GRAPHICS_EXTENSION,
+    &INVALID_OPTION,
+    &EXTRA_ALIGNMENT_TO_CR,
+    &MISSING_ENDING_BRACE,
+    &MISSING_STARTING_BRACE,
+    &EXTRA_GROUP_END,
+    &UNMATCHED_COMMAND,
+    &INCOMPLETE_CONDITIONAL,
+    &END_INSIDE_GROUP,
+    &UNCLOSED_GROUP,
+    &UNCLOSED_CONDITIONAL,
+    &MISSING_NUMBER,
+    &ILLEGAL_UNIT,
+    &MISSING_DIMENSION,
+    &DIMENSION_TOO_LARGE,
+    &ARITHMETIC_OVERFLOW,
+    &NUMBER_TOO_BIG,
+    &COMMAND_ALREADY_DEFINED,
+    &ILLEGAL_PARAMETER_NUMBER,
+    &MISPLACED_PARAMETER_CHARACTER,
+    &DEFINITION_MISMATCH,
+    &ARGUMENT_EXTRA_BRACE,
+    &PREAMBLE_ONLY,
+    &FONT_SHAPE_UNDEFINED,
+    &FONT_SHAPES_SUBSTITUTED,
+    &MISSING_CHARACTERS,
+    &FONT_NOT_LOADABLE,
+    &UNICODE_CHARACTER_NOT_SET_UP,
+    &INVALID_UTF8,
+    &INVALID_CHARACTER,
+    &UNDEFINED_KEYBOARD_CHARACTER,
+    &FLOAT_SPECIFIER_CHANGED,
+    &FLOAT_TOO_LARGE,
+    &TOO_MANY_FLOATS,
+    &MARGINPAR_MOVED,
+    &NOT_IN_OUTER_PAR_MODE,
+    &OPTION_CLASH,
+    &WRONG_LOADING_ORDER,
+    &TWO_DOCUMENT_CLASSES,
+    &PDF_STRING_TOKEN_REMOVED,
+    &BOOKMARK_LEVEL_DIFFERENCE,
+    &DUPLICATE_DESTINATION,
+    &ENGINE_ERROR,
+    &ENGINE_WARNING,
+    &LUA_ERROR,
+    &EXPL3_ERROR,
+    &UNKNOWN_KEY,
+    &TIKZ_PATH_GAVE_UP,
+    &PGFPLOTS_ERROR,
+    &PGFPLOTS_COMPATIBILITY_MODE,
+    &RERUN_BIBLIOGRAPHY_TOOL,
+    &BIBLIOGRAPHY_DATA_ENCODING,
+    &UNDEFINED_CITATION,
+    &MULTIPLY_DEFINED_CITATION,
+    &INCOMPATIBLE_PACKAGE,
+    &EMPTY_BIBLIOGRAPHY,
+    &LAYOUT_LENGTH_TOO_SMALL,
+    &GEOMETRY_OVER_SPECIFICATION,
+    &MICROTYPE_UNKNOWN_SLOT,
+    &BAD_TYPE_AREA,
+    &MISSING_HYPHENATION_PATTERNS,
+    &UNKNOWN_LANGUAGE,
+    &CAPACITY_EXCEEDED,
+    &VERB_ENDED_BY_END_OF_LINE,
+    &VERB_IN_ARGUMENT,
+    &FRAGILE_COMMAND,
+    &MISPLACED_ALIGNMENT,
+    &ILLEGAL_PREAMBLE_TOKEN,
+    &MISSING_TITLE_DATA,
+    &RERUN_FOR_PDF_DATA,
+    &UNUSED_GLOBAL_OPTIONS,
+    &FILE_WRITTEN,
+    &PROVIDED_NAME_MISMATCH,
+];
 
-    /// When a string, which is valid only in math mode, is used outside math environments.
-    ///
-    /// Example latex source:
-    /// ```latex
-    /// \documentclass{article}
-    ///
-    /// \begin{document}
-    /// _
-    /// \end{document}
-    /// ```
-    ///
-    /// Example log output:
-    /// ```txt
-    /// ! Missing $ inserted.
-    /// <inserted text>
-    ///                 $
-    /// l.4 _
-    /// ```
-    NotInMathMode(&'a str),
+pub const UNDEFINED_CONTROL_SEQUENCE: RuleInfo = RuleInfo {
+    id: "LX0001",
+    name: "undefined-control-sequence",
+    explanation: r#"When there is used a control sequence which is undefined.
 
-    /// When there is missing ending brace for a command.
-    ///
-    /// Example latex source:
-    /// ```latex
-    /// \documentclass{article}
-    ///
-    /// \begin{document}
-    /// \date{April 2018 \maketitle
-    ///
-    /// \date{April 2018 \maketitle
-    /// \end{document}
-    /// ```
-    ///
-    /// Example log output:
-    /// ```txt
-    /// Runaway argument?
-    /// {April 2018 \maketitle
-    /// ! Paragraph ended before \date was complete.
-    /// <to be read again>
-    ///                    \par
-    /// l.5
-    ///
-    /// Runaway argument?
-    /// {April 2018 \maketitle \end {document}
-    /// ! File ended while scanning use of \date.
-    /// <inserted text>
-    ///                 \par
-    /// <*> runaway_argument.tex
-    /// ```
-    RunawayArgument(&'a str),
+Example latex source:
+```latex
+\documentclass{article}
 
-    /// When a line cannot be stretched to fit.
-    ///
-    /// Example latex source:
-    /// ```latex
-    /// \documentclass{article}
-    ///
-    /// \begin{document}
-    ///
-    /// Donec nec sapien scelerisque, sagittis augue dictum, interdum nisl. \\ \\ Aenean est libero, porttitor vitae mi non, sodales mattis lectus.
-    ///
-    /// \end{document}
-    /// ```
-    ///
-    /// Example log output:
-    /// ```txt
-    /// Underfull \hbox (badness 10000) in paragraph at lines 5--6
-    ///
-    ///  []
-    /// ```
-    UnderfullHBox(&'a str, usize),
+\begin{document}
+\foo
+\end{document}
+```
 
-    /// When a line overflows maximum width.
-    ///
-    /// Example latex source:
-    /// ```latex
-    /// \documentclass{article}
-    ///
-    /// \begin{document}
-    ///
-    /// Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed in \({e^{ix} = \cos(x) + i \sin(x)}\) condimentum erat.
-    ///
-    /// \end{document}
-    /// ```
-    ///
-    /// Example log output:
-    /// ```txt
-    /// Overfull \hbox (35.0259pt too wide) in paragraph at lines 5--6
-    /// []\OT1/cmr/m/n/10 Lorem ip-sum do-lor sit amet, con-secte-tur adip-isc-ing elit
-    /// . Sed in $[]$
-    ///  []
-    /// ```
-    OverfullHBox(&'a str),
+Example log output:
+```txt
+! Undefined control sequence.
+l.4 \foo
+```"#,
+};
 
-    /// When a unknown package is tried to be used.
-    ///
-    /// Example latex source:
-    /// ```latex
-    /// \documentclass{article}
-    ///
-    /// \usepackage{missing}
-    ///
-    /// \begin{document}
-    /// \end{document}
-    /// ```
-    ///
-    /// Example log output:
-    /// ```txt
-    /// ! LaTeX Error: File `missing.sty' not found.
-    /// ```
-    MissingPackage(&'a str),
+pub const TOO_MANY_ENDING_BRACES: RuleInfo = RuleInfo {
+    id: "LX0002",
+    name: "too-many-ending-braces",
+    explanation: r#"When there is missing starting brace for a command.
 
-    /// When an invalid option is passed into a package.
-    ///
-    /// Example latex source:
-    /// ```latex
-    /// \documentclass{article}
-    ///
-    /// \usepackage[invalid]{graphicx}
-    ///
-    /// \begin{document}
-    /// \end{document}
-    /// ```
-    ///
-    /// Example log output:
-    /// ```txt
-    /// ! LaTeX Error: Unknown option `invalid' for package `graphics'.
-    /// ```
-    InvalidOption(&'a str, &'a str),
+Example latex source:
+```latex
+\documentclass{article}
 
-    /// When too many &'s are in a row of a table, array or eqnarray.
-    ///
-    /// Example of latex source:
-    /// ```latex
-    /// \documentclass{article}
-    ///
-    /// \begin{document}
-    ///
-    /// \begin{table}
-    ///   \begin{tabular}{c}
-    ///     Foo & Bar \\
-    ///   \end{tabular}
-    /// \end{table}
-    ///
-    /// \end{document}
-    /// ```
-    ///
-    /// Example log output:
-    /// ```txt
-    /// ! Extra alignment tab has been changed to \cr.
-    /// <recently read> \endtemplate
-    ///
-    /// l.7     Foo &
-    ///               Bar \\
-    /// ```
-    ExtraAlignmentToCR(&'a str),
-}
+\begin{document}
+\date April 2018}
+\end{document}
+```
 
-impl<'a> LogItemType<'a> {
-    fn get_level(&self) -> LogItemTypeLevel {
-        match *self {
-            LogItemType::UndefinedControlSequence(_)
-            | LogItemType::TooManyEndingBraces(_)
-            | LogItemType::NotInMathMode(_)
-            | LogItemType::RunawayArgument(_)
-            | LogItemType::MissingPackage(_)
-            | LogItemType::InvalidOption(_, _)
-            | LogItemType::ExtraAlignmentToCR(_) => LogItemTypeLevel::Error,
-            LogItemType::UnderfullHBox(_, _) | LogItemType::OverfullHBox(_) => {
-                LogItemTypeLevel::Warning
-            }
-        }
+Example log output:
+```txt
+! Too many }'s.
+l.4 \date April 2018}
+```"#,
+};
+
+pub const NOT_IN_MATH_MODE: RuleInfo = RuleInfo {
+    id: "LX0003",
+    name: "not-in-math-mode",
+    explanation: r#"When a string, which is valid only in math mode, is used outside math environments.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+_
+\end{document}
+```
+
+Example log output:
+```txt
+! Missing $ inserted.
+<inserted text>
+                $
+l.4 _
+```"#,
+};
+
+pub const RUNAWAY_ARGUMENT: RuleInfo = RuleInfo {
+    id: "LX0004",
+    name: "runaway-argument",
+    explanation: r#"When there is missing ending brace for a command.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\date{April 2018 \maketitle
+
+\date{April 2018 \maketitle
+\end{document}
+```
+
+Example log output:
+```txt
+Runaway argument?
+{April 2018 \maketitle
+! Paragraph ended before \date was complete.
+<to be read again>
+                   \par
+l.5
+
+Runaway argument?
+{April 2018 \maketitle \end {document}
+! File ended while scanning use of \date.
+<inserted text>
+                \par
+<*> runaway_argument.tex
+```"#,
+};
+
+pub const UNDERFULL_HBOX: RuleInfo = RuleInfo {
+    id: "LX0005",
+    name: "underfull-hbox",
+    explanation: r#"When a line cannot be stretched to fit.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+
+Donec nec sapien scelerisque, sagittis augue dictum, interdum nisl. \\ \\ Aenean est libero, porttitor vitae mi non, sodales mattis lectus.
+
+\end{document}
+```
+
+Example log output:
+```txt
+Underfull \hbox (badness 10000) in paragraph at lines 5--6
+
+ []
+```"#,
+};
+
+pub const OVERFULL_HBOX: RuleInfo = RuleInfo {
+    id: "LX0006",
+    name: "overfull-hbox",
+    explanation: r#"When a line overflows maximum width.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+
+Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed in \({e^{ix} = \cos(x) + i \sin(x)}\) condimentum erat.
+
+\end{document}
+```
+
+Example log output:
+```txt
+Overfull \hbox (35.0259pt too wide) in paragraph at lines 5--6
+[]\OT1/cmr/m/n/10 Lorem ip-sum do-lor sit amet, con-secte-tur adip-isc-ing elit
+. Sed in $[]$
+ []
+```"#,
+};
+
+pub const MISSING_FILE: RuleInfo = RuleInfo {
+    id: "LX0007",
+    name: "missing-file",
+    explanation: r#"When a file cannot be found. The kind of the file (class, package, graphic, input,
+bibliography or font) is determined from its extension or from the command which requested
+it.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage{missing}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: File `missing.sty' not found.
+
+Type X to quit or <RETURN> to proceed,
+or enter new name. (Default extension: sty)
+
+Enter file name:
+! Emergency stop.
+<read *>
+
+l.3 \usepackage
+               {missing}^^M
+```
+
+Other forms of the log output:
+```txt
+! I can't find file `chapter3'.
+l.5 \input chapter3
+
+No file thesis.bbl.
+```"#,
+};
+
+pub const UNKNOWN_GRAPHICS_EXTENSION: RuleInfo = RuleInfo {
+    id: "LX0008",
+    name: "unknown-graphics-extension",
+    explanation: r#"When an image with unsupported extension is included.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage{graphicx}
+
+\begin{document}
+\includegraphics{figure.svg}
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: Unknown graphics extension: .svg.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.6 \includegraphics{figure.svg}
+```"#,
+};
+
+pub const INVALID_OPTION: RuleInfo = RuleInfo {
+    id: "LX0009",
+    name: "invalid-option",
+    explanation: r#"When an invalid option is passed into a package.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage[invalid]{graphicx}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: Unknown option `invalid' for package `graphics'.
+```"#,
+};
+
+pub const EXTRA_ALIGNMENT_TO_CR: RuleInfo = RuleInfo {
+    id: "LX0010",
+    name: "extra-alignment-to-cr",
+    explanation: r#"When too many &'s are in a row of a table, array or eqnarray.
+
+Example of latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+
+\begin{table}
+  \begin{tabular}{c}
+    Foo & Bar \\
+  \end{tabular}
+\end{table}
+
+\end{document}
+```
+
+Example log output:
+```txt
+! Extra alignment tab has been changed to \cr.
+<recently read> \endtemplate
+
+l.7     Foo &
+              Bar \\
+```
+
+The environment is reported when it is recognized from the context, for example longtable
+or tabularx."#,
+};
+
+pub const MISSING_ENDING_BRACE: RuleInfo = RuleInfo {
+    id: "LX0011",
+    name: "missing-ending-brace",
+    explanation: r#"When a group is ended by something else than a closing brace, typically in math mode.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+$x^{2$
+\end{document}
+```
+
+Example log output:
+```txt
+! Missing } inserted.
+<inserted text>
+                }
+l.4 $x^{2$
+```"#,
+};
+
+pub const MISSING_STARTING_BRACE: RuleInfo = RuleInfo {
+    id: "LX0012",
+    name: "missing-starting-brace",
+    explanation: r#"When TeX expects a group to start, but there is no opening brace.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\hbox to 2cm foo
+\end{document}
+```
+
+Example log output:
+```txt
+! Missing { inserted.
+<to be read again>
+                   f
+l.4 \hbox to 2cm f
+                  oo
+```"#,
+};
+
+pub const EXTRA_GROUP_END: RuleInfo = RuleInfo {
+    id: "LX0013",
+    name: "extra-group-end",
+    explanation: r#"When a closing brace is found inside an environment or other group which was not started
+with a brace.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\begin{center}
+foo}
+\end{center}
+\end{document}
+```
+
+Example log output:
+```txt
+! Extra }, or forgotten \endgroup.
+l.5 foo}
+```"#,
+};
+
+pub const UNMATCHED_COMMAND: RuleInfo = RuleInfo {
+    id: "LX0014",
+    name: "unmatched-command",
+    explanation: r#"When a command which ends or continues a construct (like `\fi`, `\else` or `\endcsname`) is
+used without the corresponding beginning.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\fi
+\end{document}
+```
+
+Example log output:
+```txt
+! Extra \fi.
+l.4 \fi
+```"#,
+};
+
+pub const INCOMPLETE_CONDITIONAL: RuleInfo = RuleInfo {
+    id: "LX0015",
+    name: "incomplete-conditional",
+    explanation: r#"When a conditional is not terminated with `\fi` before the end of the file.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\iffalse
+foo
+\end{document}
+```
+
+Example log output:
+```txt
+! Incomplete \iffalse; all text was ignored after line 4.
+<inserted text>
+                \fi
+```"#,
+};
+
+pub const END_INSIDE_GROUP: RuleInfo = RuleInfo {
+    id: "LX0016",
+    name: "end-inside-group",
+    explanation: r#"When the document ends while some groups are still open. This is reported only by engines
+which do not list the open groups (see `UnclosedGroup`).
+
+Example log output:
+```txt
+(\end occurred inside a group at level 1)
+```"#,
+};
+
+pub const UNCLOSED_GROUP: RuleInfo = RuleInfo {
+    id: "LX0017",
+    name: "unclosed-group",
+    explanation: r#"When a group is still open at the end of the document. The type of the group, its level and
+the string which started it are reported.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+{foo
+\end{document}
+```
+
+Example log output:
+```txt
+(\end occurred inside a group at level 1)
+
+### simple group (level 1) entered at line 4 ({)
+### bottom level
+```"#,
+};
+
+pub const UNCLOSED_CONDITIONAL: RuleInfo = RuleInfo {
+    id: "LX0018",
+    name: "unclosed-conditional",
+    explanation: r#"When a conditional is still open at the end of the document.
+
+Example log output:
+```txt
+(\end occurred when \iftrue on line 4 was incomplete)
+```"#,
+};
+
+pub const MISSING_NUMBER: RuleInfo = RuleInfo {
+    id: "LX0019",
+    name: "missing-number",
+    explanation: r#"When a number is expected, but something else is found.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\setcounter{page}{two}
+\end{document}
+```
+
+Example log output:
+```txt
+! Missing number, treated as zero.
+<to be read again>
+                   t
+l.4 \setcounter{page}{two}
+```"#,
+};
+
+pub const ILLEGAL_UNIT: RuleInfo = RuleInfo {
+    id: "LX0020",
+    name: "illegal-unit",
+    explanation: r#"When a length is given with an unknown unit or without any unit.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\setlength{\parindent}{2}
+\end{document}
+```
+
+Example log output:
+```txt
+! Illegal unit of measure (pt inserted).
+<to be read again>
+                   \relax
+l.4 \setlength{\parindent}{2}
+```"#,
+};
+
+pub const MISSING_DIMENSION: RuleInfo = RuleInfo {
+    id: "LX0021",
+    name: "missing-dimension",
+    explanation: r#"When a length is expected, but neither number nor unit is found. TeX reports it as missing
+number immediately followed by illegal unit of measure, which is usually caused by a mistyped
+length macro.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\setlength{\parindent}{cm}
+\end{document}
+```
+
+Example log output:
+```txt
+! Missing number, treated as zero.
+<to be read again>
+                   c
+l.4 \setlength{\parindent}{cm}
+
+! Illegal unit of measure (pt inserted).
+<to be read again>
+                   c
+l.4 \setlength{\parindent}{cm}
+```"#,
+};
+
+pub const DIMENSION_TOO_LARGE: RuleInfo = RuleInfo {
+    id: "LX0022",
+    name: "dimension-too-large",
+    explanation: r#"When a length exceeds the maximum which TeX can handle (16383.99999pt).
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\setlength{\parindent}{20000pt}
+\end{document}
+```
+
+Example log output:
+```txt
+! Dimension too large.
+<to be read again>
+                   \relax
+l.4 \setlength{\parindent}{20000pt}
+```"#,
+};
+
+pub const ARITHMETIC_OVERFLOW: RuleInfo = RuleInfo {
+    id: "LX0023",
+    name: "arithmetic-overflow",
+    explanation: r#"When a result of an arithmetic operation cannot be represented, for example on division by
+zero.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\newcount\foo \divide\foo by 0
+\end{document}
+```
+
+Example log output:
+```txt
+! Arithmetic overflow.
+l.4 \newcount\foo \divide\foo by 0
+```"#,
+};
+
+pub const NUMBER_TOO_BIG: RuleInfo = RuleInfo {
+    id: "LX0024",
+    name: "number-too-big",
+    explanation: r#"When a number exceeds the maximum which TeX can handle (2147483647).
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\setcounter{page}{3000000000}
+\end{document}
+```
+
+Example log output:
+```txt
+! Number too big.
+<to be read again>
+                   \relax
+l.4 \setcounter{page}{3000000000}
+```"#,
+};
+
+pub const COMMAND_ALREADY_DEFINED: RuleInfo = RuleInfo {
+    id: "LX0025",
+    name: "command-already-defined",
+    explanation: r#"When a command is defined with `\newcommand` (or similar), but it already exists.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\newcommand{\foo}{foo}
+\newcommand{\foo}{bar}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: Command \foo already defined.
+               Or name \end... illegal, see p.192 of the manual.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.4 \newcommand{\foo}{bar}
+```"#,
+};
+
+pub const ILLEGAL_PARAMETER_NUMBER: RuleInfo = RuleInfo {
+    id: "LX0026",
+    name: "illegal-parameter-number",
+    explanation: r#"When a command definition uses a parameter with higher number than the number of declared
+parameters.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\newcommand{\foo}[1]{#2}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+! Illegal parameter number in definition of \foo.
+<to be read again>
+                   2
+l.3 \newcommand{\foo}[1]{#2}
+```"#,
+};
+
+pub const MISPLACED_PARAMETER_CHARACTER: RuleInfo = RuleInfo {
+    id: "LX0027",
+    name: "misplaced-parameter-character",
+    explanation: r#"When the macro parameter character `#` is used outside a command definition.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+Issue #1
+\end{document}
+```
+
+Example log output:
+```txt
+! You can't use `macro parameter character #' in horizontal mode.
+l.4 Issue #
+           1
+```"#,
+};
+
+pub const DEFINITION_MISMATCH: RuleInfo = RuleInfo {
+    id: "LX0028",
+    name: "definition-mismatch",
+    explanation: r#"When a command defined with delimited parameters is used without the delimiters.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\def\foo(#1){#1}
+
+\begin{document}
+\foo[bar]
+\end{document}
+```
+
+Example log output:
+```txt
+! Use of \foo doesn't match its definition.
+l.6 \foo[
+          bar]
+```"#,
+};
+
+pub const ARGUMENT_EXTRA_BRACE: RuleInfo = RuleInfo {
+    id: "LX0029",
+    name: "argument-extra-brace",
+    explanation: r#"When an argument of a command contains unbalanced closing brace.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\newcommand{\foo}[1]{#1}
+
+\begin{document}
+{\foo}
+\end{document}
+```
+
+Example log output:
+```txt
+! Argument of \foo has an extra }.
+<inserted text>
+                \par
+l.6 {\foo}
+```"#,
+};
+
+pub const PREAMBLE_ONLY: RuleInfo = RuleInfo {
+    id: "LX0030",
+    name: "preamble-only",
+    explanation: r#"When a command which is allowed only in preamble is used in the document body.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\usepackage{graphicx}
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: Can be used only in preamble.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.4 \usepackage
+               {graphicx}
+```"#,
+};
+
+pub const FONT_SHAPE_UNDEFINED: RuleInfo = RuleInfo {
+    id: "LX0031",
+    name: "font-shape-undefined",
+    explanation: r#"When a requested font shape (combination of encoding, family, series and shape) does not
+exist and is substituted by another one.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\textsc{\textit{Foo}}
+\end{document}
+```
+
+Example log output:
+```txt
+LaTeX Font Warning: Font shape `OT1/cmr/m/scit' undefined
+(Font)              using `OT1/cmr/m/n' instead on input line 4.
+```"#,
+};
+
+pub const FONT_SHAPES_SUBSTITUTED: RuleInfo = RuleInfo {
+    id: "LX0032",
+    name: "font-shapes-substituted",
+    explanation: r#"When some font shapes were substituted. This is the summary at the end of the log, the
+particular shapes are reported by `FontShapeUndefined`.
+
+Example log output:
+```txt
+LaTeX Font Warning: Some font shapes were not available, defaults substituted.
+```"#,
+};
+
+pub const MISSING_CHARACTERS: RuleInfo = RuleInfo {
+    id: "LX0033",
+    name: "missing-characters",
+    explanation: r#"When a font does not contain some characters. These characters are silently left out from
+the output. All missing characters of a font are reported together.
+
+Example latex source (compiled with XeLaTeX or LuaLaTeX):
+```latex
+\documentclass{article}
+
+\usepackage{fontspec}
+
+\begin{document}
+\fontspec{Latin Modern Roman}
+你好
+\end{document}
+```
+
+Example log output:
+```txt
+Missing character: There is no 你 (U+4F60) in font [lmroman10-regular]:mapping=tex-text;!
+Missing character: There is no 好 (U+597D) in font [lmroman10-regular]:mapping=tex-text;!
+```"#,
+};
+
+pub const FONT_NOT_LOADABLE: RuleInfo = RuleInfo {
+    id: "LX0034",
+    name: "font-not-loadable",
+    explanation: r#"When a font cannot be loaded, usually because it is not installed.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\font\x=missing at 10pt
+\end{document}
+```
+
+Example log output:
+```txt
+! Font \x=missing at 10.0pt not loadable: Metric (TFM) file not found.
+<to be read again>
+                   \par
+l.4 \font\x=missing at 10pt
+```"#,
+};
+
+pub const UNICODE_CHARACTER_NOT_SET_UP: RuleInfo = RuleInfo {
+    id: "LX0035",
+    name: "unicode-character-not-set-up",
+    explanation: r#"When a Unicode character which LaTeX does not know how to typeset is used. Replacement is
+suggested for common characters.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+Foo ☃ bar
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: Unicode character ☃ (U+2603)
+               not set up for use with LaTeX.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.4 Foo ☃
+          bar
+```"#,
+};
+
+pub const INVALID_UTF8: RuleInfo = RuleInfo {
+    id: "LX0036",
+    name: "invalid-utf8",
+    explanation: r#"When the source file is not valid UTF-8, usually because it is saved in a different
+encoding.
+
+Example log output:
+```txt
+! Package inputenc Error: Invalid UTF-8 byte "E9.
+
+See the inputenc package documentation for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.4 Caf^^e9
+```"#,
+};
+
+pub const INVALID_CHARACTER: RuleInfo = RuleInfo {
+    id: "LX0037",
+    name: "invalid-character",
+    explanation: r#"When a line contains a character which TeX considers as invalid (category code 15).
+
+Example log output:
+```txt
+! Text line contains an invalid character.
+l.4 Foo^^?
+          bar
+```"#,
+};
+
+pub const UNDEFINED_KEYBOARD_CHARACTER: RuleInfo = RuleInfo {
+    id: "LX0038",
+    name: "undefined-keyboard-character",
+    explanation: r#"When a character is not defined in 8-bit input encoding selected by inputenc.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage[latin1]{inputenc}
+
+\begin{document}
+^^80
+\end{document}
+```
+
+Example log output:
+```txt
+! Package inputenc Error: Keyboard character used is undefined
+(inputenc)                in inputencoding `latin1'.
+
+See the inputenc package documentation for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.6 ^^80
+```"#,
+};
+
+pub const FLOAT_SPECIFIER_CHANGED: RuleInfo = RuleInfo {
+    id: "LX0039",
+    name: "float-specifier-changed",
+    explanation: r#"When a float placement specifier is too restrictive and LaTeX relaxes it.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\begin{figure}[h]
+Foo
+\end{figure}
+\end{document}
+```
+
+Example log output:
+```txt
+LaTeX Warning: `h' float specifier changed to `ht'.
+```"#,
+};
+
+pub const FLOAT_TOO_LARGE: RuleInfo = RuleInfo {
+    id: "LX0040",
+    name: "float-too-large",
+    explanation: r#"When a float is larger than the page. The amount of overflow is reported.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\begin{figure}
+\rule{1cm}{30cm}
+\end{figure}
+\end{document}
+```
+
+Example log output:
+```txt
+LaTeX Warning: Float too large for page by 266.59pt on input line 6.
+```"#,
+};
+
+pub const TOO_MANY_FLOATS: RuleInfo = RuleInfo {
+    id: "LX0041",
+    name: "too-many-floats",
+    explanation: r#"When there are too many floats waiting to be placed (LaTeX can hold only 18 of them).
+
+Example log output:
+```txt
+! LaTeX Error: Too many unprocessed floats.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.120 \begin{figure}
+```"#,
+};
+
+pub const MARGINPAR_MOVED: RuleInfo = RuleInfo {
+    id: "LX0042",
+    name: "marginpar-moved",
+    explanation: r#"When a margin note is moved down to avoid overlapping with the previous one. The page of
+the margin note is reported.
+
+Example log output:
+```txt
+LaTeX Warning: Marginpar on page 4 moved.
+```"#,
+};
+
+pub const NOT_IN_OUTER_PAR_MODE: RuleInfo = RuleInfo {
+    id: "LX0043",
+    name: "not-in-outer-par-mode",
+    explanation: r#"When a float or a margin note is used where it is not allowed, for example inside a box or
+another float.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\fbox{\marginpar{Foo}}
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: Not in outer par mode.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.4 \fbox{\marginpar{Foo}}
+```"#,
+};
+
+pub const OPTION_CLASH: RuleInfo = RuleInfo {
+    id: "LX0044",
+    name: "option-clash",
+    explanation: r#"When a package is loaded more than once with different options. The first load wins and
+the options of the other loads are ignored. Both option lists are reported.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage[dvipsnames]{xcolor}
+\usepackage[table]{xcolor}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: Option clash for package xcolor.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.4 \usepackage[table]{xcolor}
+
+The package xcolor has already been loaded with options:
+  [dvipsnames]
+There has now been an attempt to load it with options
+  [table]
+Adding the global options:
+  dvipsnames,table
+to your \documentclass declaration may fix this.
+Try typing  <return>  to proceed.
+```"#,
+};
+
+pub const WRONG_LOADING_ORDER: RuleInfo = RuleInfo {
+    id: "LX0045",
+    name: "wrong-loading-order",
+    explanation: r#"When a package reports that it was loaded in a wrong order with respect to another package.
+
+Example log output:
+```txt
+Package hyperref Warning: You have loaded hyperref before float
+(hyperref)                on input line 5.
+```"#,
+};
+
+pub const TWO_DOCUMENT_CLASSES: RuleInfo = RuleInfo {
+    id: "LX0046",
+    name: "two-document-classes",
+    explanation: r#"When the document class is declared more than once.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\documentclass{report}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: Two \documentclass or \documentstyle commands.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.3 \documentclass
+                  {report}
+```"#,
+};
+
+pub const PDF_STRING_TOKEN_REMOVED: RuleInfo = RuleInfo {
+    id: "LX0047",
+    name: "pdf-string-token-removed",
+    explanation: r#"When a token which cannot be represented in PDF strings (bookmarks, document properties) is
+removed by hyperref.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage{hyperref}
+
+\begin{document}
+\section{Foo $x$}
+\end{document}
+```
+
+Example log output:
+```txt
+Package hyperref Warning: Token not allowed in a PDF string (PDFDocEncoding):
+(hyperref)                removing `math shift' on input line 6.
+```"#,
+};
+
+pub const BOOKMARK_LEVEL_DIFFERENCE: RuleInfo = RuleInfo {
+    id: "LX0048",
+    name: "bookmark-level-difference",
+    explanation: r#"When a sectioning level is skipped, so the bookmark levels differ by more than one.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage{hyperref}
+
+\begin{document}
+\section{Foo}
+\subsubsection{Bar}
+\end{document}
+```
+
+Example log output:
+```txt
+Package hyperref Warning: Difference (2) between bookmark levels is greater
+(hyperref)                than one, level fixed on input line 7.
+```"#,
+};
+
+pub const DUPLICATE_DESTINATION: RuleInfo = RuleInfo {
+    id: "LX0049",
+    name: "duplicate-destination",
+    explanation: r#"When two link destinations have the same name, typically when a counter (like page) is reset
+and then numbered the same way again.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage{hyperref}
+
+\begin{document}
+Foo
+\newpage
+\setcounter{page}{1}
+Bar
+\end{document}
+```
+
+Example log output:
+```txt
+pdfTeX warning (ext4): destination with the same identifier (name{page.1}) has
+been already used, duplicate ignored
+<to be read again>
+                   \relax
+l.9 \end{document}
+```"#,
+};
+
+pub const ENGINE_ERROR: RuleInfo = RuleInfo {
+    id: "LX0050",
+    name: "engine-error",
+    explanation: r#"When the engine itself (pdfTeX, XeTeX's xdvipdfmx or LuaTeX) reports an error, usually
+related to links, image inclusion, fonts or PDF output. The engine, the area, the file
+named in the message and the message itself are reported.
+
+Example log output:
+```txt
+! pdfTeX error (ext1): \pdfendlink ended up in different nesting level than \pd
+fstartlink.
+<to be read again>
+                   \endgroup
+l.30 \end{itemize}
+
+error: (pdf backend): 'endlink' ended up in different nesting level than 'startlink'
+```"#,
+};
+
+pub const ENGINE_WARNING: RuleInfo = RuleInfo {
+    id: "LX0051",
+    name: "engine-warning",
+    explanation: r#"When the engine itself (pdfTeX, XeTeX's xdvipdfmx or LuaTeX) reports a warning. See
+`EngineError`.
+
+Example log output:
+```txt
+pdfTeX warning: pdflatex (file ./fig.pdf): PDF inclusion: found PDF version <1.7>, but
+ at most version <1.5> allowed
+
+xdvipdfmx:warning: Could not locate a virtual/physical font for TFM "foo".
+```"#,
+};
+
+pub const LUA_ERROR: RuleInfo = RuleInfo {
+    id: "LX0052",
+    name: "lua-error",
+    explanation: r#"When a Lua code executed by LuaTeX (in `\directlua` or in a package like luaotfload)
+raises an error. The Lua chunk name, the line in the chunk, the message and the frames of
+stack traceback are reported.
+
+Example latex source (compiled with LuaLaTeX):
+```latex
+\documentclass{article}
+
+\begin{document}
+\directlua{foo()}
+\end{document}
+```
+
+Example log output:
+```txt
+! LuaTeX error [\directlua]:1: attempt to call a nil value (global 'foo')
+stack traceback:
+        [C]: in global 'foo'
+        [\directlua]:1: in main chunk.
+l.4 \directlua{foo()}
+```"#,
+};
+
+pub const EXPL3_ERROR: RuleInfo = RuleInfo {
+    id: "LX0053",
+    name: "expl3-error",
+    explanation: r#"When a package written in LaTeX3 (expl3) reports an error. These errors are spread over
+multiple lines prefixed with the module name and they have the help text in a box after the
+location. The module, the message and the help text (if present) are reported.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage{siunitx}
+
+\begin{document}
+\num{1,2,3}
+\end{document}
+```
+
+Example log output:
+```txt
+! Package siunitx Error: Invalid number '1,2,3'.
+
+For immediate help type H <return>.
+ ...
+
+l.6 \num{1,2,3}
+
+|'''''''''''''''''''''''''''''''''''''''''''''''
+| The input '1,2,3' could not be parsed as a number.
+|...............................................
+```"#,
+};
+
+pub const UNKNOWN_KEY: RuleInfo = RuleInfo {
+    id: "LX0054",
+    name: "unknown-key",
+    explanation: r#"When an unknown key is used in options of TikZ, pgfplots or other package using pgfkeys.
+The key path is split into the family and the key name and a similar known key is suggested
+if there is one.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage{tikz}
+
+\begin{document}
+\tikz \draw[colr=red] (0,0) -- (1,1);
+\end{document}
+```
+
+Example log output:
+```txt
+! Package pgfkeys Error: I do not know the key '/tikz/colr', to which you passed
+ 'red', and I am going to ignore it. Perhaps you misspelled it.
+
+See the pgfkeys package documentation for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.6 \tikz \draw[colr=red]
+                         (0,0) -- (1,1);
+```"#,
+};
+
+pub const TIKZ_PATH_GAVE_UP: RuleInfo = RuleInfo {
+    id: "LX0055",
+    name: "tikz-path-gave-up",
+    explanation: r#"When TikZ cannot parse a path, usually because of a missing semicolon at its end.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage{tikz}
+
+\begin{document}
+\begin{tikzpicture}
+\draw (0,0) -- (1,1)
+\end{tikzpicture}
+\end{document}
+```
+
+Example log output:
+```txt
+! Package tikz Error: Giving up on this path. Did you forget a semicolon?.
+
+See the tikz package documentation for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.8 \end
+         {tikzpicture}
+```"#,
+};
+
+pub const PGFPLOTS_ERROR: RuleInfo = RuleInfo {
+    id: "LX0056",
+    name: "pgfplots-error",
+    explanation: r#"When pgfplots reports an error.
+
+Example log output:
+```txt
+! Package pgfplots Error: Sorry, the requested column number '2' in table 'data
+.dat' does not exist!? Please verify you used the correct index 0 <= i < N..
+
+See the pgfplots package documentation for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.9 \addplot table[y index=2] {data.dat};
+```"#,
+};
+
+pub const PGFPLOTS_COMPATIBILITY_MODE: RuleInfo = RuleInfo {
+    id: "LX0057",
+    name: "pgfplots-compatibility-mode",
+    explanation: r#"When pgfplots runs in backwards compatibility mode, because the compatibility level is not
+set. The suggested compatibility level is reported.
+
+Example log output:
+```txt
+Package pgfplots Warning: running in backwards compatibility mode (unsuitable t
+ick labels; missing features). Consider writing \pgfplotsset{compat=1.16} into
+ your preamble.
+ on input line 4.
+```"#,
+};
+
+pub const RERUN_BIBLIOGRAPHY_TOOL: RuleInfo = RuleInfo {
+    id: "LX0058",
+    name: "rerun-bibliography-tool",
+    explanation: r#"When the bibliography has to be generated by Biber or BibTeX and LaTeX has to be run
+again. The tool and the file to run it on are reported.
+
+Example log output:
+```txt
+Package biblatex Warning: Please (re)run Biber on the file:
+(biblatex)                thesis
+(biblatex)                and rerun LaTeX afterwards.
+```"#,
+};
+
+pub const BIBLIOGRAPHY_DATA_ENCODING: RuleInfo = RuleInfo {
+    id: "LX0059",
+    name: "bibliography-data-encoding",
+    explanation: r#"When biblatex reports a problem with encoding of bibliography data.
+
+Example log output:
+```txt
+Package biblatex Warning: Data encoding is 'utf8'.
+(biblatex)                Use backend=biber.
+```"#,
+};
+
+pub const UNDEFINED_CITATION: RuleInfo = RuleInfo {
+    id: "LX0060",
+    name: "undefined-citation",
+    explanation: r#"When a citation refers to a key which is not in the bibliography.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\cite{foo}
+\end{document}
+```
+
+Example log output:
+```txt
+LaTeX Warning: Citation `foo' on page 1 undefined on input line 4.
+```"#,
+};
+
+pub const MULTIPLY_DEFINED_CITATION: RuleInfo = RuleInfo {
+    id: "LX0061",
+    name: "multiply-defined-citation",
+    explanation: r#"When a bibliography entry with the same key is defined more than once. The key is reported
+if it is known, natbib reports it in a separate warning.
+
+Example log output:
+```txt
+Package natbib Warning: Citation `foo' multiply defined.
+
+Package natbib Warning: There were multiply defined citations.
+```"#,
+};
+
+pub const INCOMPATIBLE_PACKAGE: RuleInfo = RuleInfo {
+    id: "LX0062",
+    name: "incompatible-package",
+    explanation: r#"When a package cannot be used together with a bibliography package.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage{cite}
+\usepackage{biblatex}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+! Package biblatex Error: Incompatible package 'cite'.
+
+See the biblatex package documentation for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.4 \usepackage
+               {biblatex}
+```"#,
+};
+
+pub const EMPTY_BIBLIOGRAPHY: RuleInfo = RuleInfo {
+    id: "LX0063",
+    name: "empty-bibliography",
+    explanation: r#"When the bibliography environment has no entries.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\begin{thebibliography}{9}\end{thebibliography}
+\end{document}
+```
+
+Example log output:
+```txt
+LaTeX Warning: Empty `thebibliography' environment on input line 4.
+```"#,
+};
+
+pub const LAYOUT_LENGTH_TOO_SMALL: RuleInfo = RuleInfo {
+    id: "LX0064",
+    name: "layout-length-too-small",
+    explanation: r#"When a length of the page layout is too small for the content, for example the height of a
+fancyhdr header. The length, its current value and the required value are reported.
+
+Example log output:
+```txt
+Package fancyhdr Warning: \headheight is too small (12.0pt):
+ Make it at least 14.49998pt.
+ We now make it that large for the rest of the document.
+ This may cause the page layout to be inconsistent, however.
+```"#,
+};
+
+pub const GEOMETRY_OVER_SPECIFICATION: RuleInfo = RuleInfo {
+    id: "LX0065",
+    name: "geometry-over-specification",
+    explanation: r#"When the geometry package is given more dimensions than it can satisfy in one direction.
+The direction and the ignored dimension with its value are reported.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage[a4paper,width=15cm,left=3cm,right=3cm]{geometry}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+Package geometry Warning: Over-specification in `h'-direction.
+    `width' (426.79134pt) is ignored.
+```"#,
+};
+
+pub const MICROTYPE_UNKNOWN_SLOT: RuleInfo = RuleInfo {
+    id: "LX0066",
+    name: "microtype-unknown-slot",
+    explanation: r#"When microtype settings refer to a character which is not in the font encoding.
+
+Example log output:
+```txt
+Package microtype Warning: Unknown slot number of character
+(microtype)                `\textcent'
+(microtype)                in font encoding `T1' in inheritance list
+(microtype)                `T1-default' on input line 1047.
+```"#,
+};
+
+pub const BAD_TYPE_AREA: RuleInfo = RuleInfo {
+    id: "LX0067",
+    name: "bad-type-area",
+    explanation: r#"When KOMA-Script typearea computes a line width very different from the expected one.
+
+Example log output:
+```txt
+Package typearea Warning: Bad type area settings!
+(typearea)                The detected line width is about 64%
+(typearea)                larger than the heuristically detected line width.
+(typearea)                You should change the DIV value or paper size.
+```"#,
+};
+
+pub const MISSING_HYPHENATION_PATTERNS: RuleInfo = RuleInfo {
+    id: "LX0068",
+    name: "missing-hyphenation-patterns",
+    explanation: r#"When hyphenation patterns of a language are not available in the format, so the text in
+that language is hyphenated incorrectly or not at all.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage[czech]{babel}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+Package babel Warning: No hyphenation patterns were preloaded for
+(babel)                the language `czech' into the format.
+(babel)                Please, configure your TeX system to add them and
+(babel)                rebuild the format. Now I will use the patterns
+(babel)                preloaded for english instead on input line 5.
+```"#,
+};
+
+pub const UNKNOWN_LANGUAGE: RuleInfo = RuleInfo {
+    id: "LX0069",
+    name: "unknown-language",
+    explanation: r#"When babel or polyglossia does not know a language, which means the language definition
+file is not installed or the name is misspelled. The package and the language are reported.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage[czech]{babel}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+! Package babel Error: Unknown option `czech'. Either you misspelled it
+(babel)                or the language definition file czech.ldf was not found.
+```"#,
+};
+
+pub const CAPACITY_EXCEEDED: RuleInfo = RuleInfo {
+    id: "LX0070",
+    name: "capacity-exceeded",
+    explanation: r#"When TeX runs out of some of its memory. The capacity, its limit and the macro which is
+suspected of infinite recursion (if any) are reported.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\def\foo{\foo x}\foo
+\end{document}
+```
+
+Example log output:
+```txt
+! TeX capacity exceeded, sorry [input stack size=5000].
+\foo ->\foo
+             x
+l.4 \def\foo{\foo x}\foo
+```"#,
+};
+
+pub const VERB_ENDED_BY_END_OF_LINE: RuleInfo = RuleInfo {
+    id: "LX0071",
+    name: "verb-ended-by-end-of-line",
+    explanation: r#"When `\verb` is not ended on the same line, usually because the delimiter is missing.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\verb|foo
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: \verb ended by end of line.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.4 \verb|foo
+```"#,
+};
+
+pub const VERB_IN_ARGUMENT: RuleInfo = RuleInfo {
+    id: "LX0072",
+    name: "verb-in-argument",
+    explanation: r#"When `\verb` is used in an argument of another command.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\section{\verb|foo|}
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: \verb illegal in argument.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.4 \section{\verb|foo|}
+```"#,
+};
+
+pub const FRAGILE_COMMAND: RuleInfo = RuleInfo {
+    id: "LX0073",
+    name: "fragile-command",
+    explanation: r#"When a fragile command is used in a moving argument (such as a section title or a caption)
+without `\protect`. The fragile command (if it is known) and the internal command which
+processed the moving argument are reported.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\section{Foo\footnote{Bar}}
+\end{document}
+```
+
+Example log output:
+```txt
+! Argument of \@sect has an extra }.
+<inserted text>
+                \par
+l.4 \section{Foo\footnote{Bar}}
+```"#,
+};
+
+pub const MISPLACED_ALIGNMENT: RuleInfo = RuleInfo {
+    id: "LX0074",
+    name: "misplaced-alignment",
+    explanation: r#"When an alignment character or command is used outside of a table or at a wrong place in
+it. The character or command (`&`, `\noalign` or `\omit`) and the row are reported.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+Foo & Bar
+\end{document}
+```
+
+Example log output:
+```txt
+! Misplaced alignment tab character &.
+l.4 Foo &
+          Bar
+```"#,
+};
+
+pub const ILLEGAL_PREAMBLE_TOKEN: RuleInfo = RuleInfo {
+    id: "LX0075",
+    name: "illegal-preamble-token",
+    explanation: r#"When a column specification of a table contains an unknown token. The token (if reported)
+and the column specification are reported.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\usepackage{array}
+
+\begin{document}
+\begin{tabular}{x}
+\end{tabular}
+\end{document}
+```
+
+Example log output:
+```txt
+! Package array Error:  Illegal pream-token (x): `c' used.
+
+See the array package documentation for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.6 \begin{tabular}{x}
+```"#,
+};
+
+pub const MISSING_TITLE_DATA: RuleInfo = RuleInfo {
+    id: "LX0076",
+    name: "missing-title-data",
+    explanation: r#"When `\maketitle` is used without the author or the title of the document. Missing title is
+an error, missing author is reported only for information.
+
+Example latex source:
+```latex
+\documentclass{article}
+
+\begin{document}
+\maketitle
+\end{document}
+```
+
+Example log output:
+```txt
+! LaTeX Error: No \title given.
+
+See the LaTeX manual or LaTeX Companion for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.4 \maketitle
+
+LaTeX Warning: No \author given.
+```"#,
+};
+
+pub const RERUN_FOR_PDF_DATA: RuleInfo = RuleInfo {
+    id: "LX0077",
+    name: "rerun-for-pdf-data",
+    explanation: r#"When hyperref needs another run to get some PDF data right.
+
+Example log output:
+```txt
+Package hyperref Warning: Rerun to get /PageLabels entry.
+```"#,
+};
+
+pub const UNUSED_GLOBAL_OPTIONS: RuleInfo = RuleInfo {
+    id: "LX0078",
+    name: "unused-global-options",
+    explanation: r#"When global options given to the document class are not used by the class nor by any
+package, which usually means they are misspelled.
+
+Example latex source:
+```latex
+\documentclass[a4]{article}
+
+\begin{document}
+\end{document}
+```
+
+Example log output:
+```txt
+LaTeX Warning: Unused global option(s):
+    [a4].
+```"#,
+};
+
+pub const FILE_WRITTEN: RuleInfo = RuleInfo {
+    id: "LX0079",
+    name: "file-written",
+    explanation: r#"When a file is written by the filecontents environment.
+
+Example log output:
+```txt
+LaTeX Warning: Writing file `./foo.tex'.
+```"#,
+};
+
+pub const PROVIDED_NAME_MISMATCH: RuleInfo = RuleInfo {
+    id: "LX0080",
+    name: "provided-name-mismatch",
+    explanation: r#"When a document class or package provides a different name than the requested one. The
+kind, the requested name and the provided name are reported.
+
+Example log output:
+```txt
+LaTeX Warning: You have requested document class `foo',
+               but the document class provides `bar'.
+```"#,
+};
+
+/// Finds a rule referred to by its identifier (`LX0006`), its full name (`overfull-hbox`) or
+/// a prefix of words in its name (`overfull`). A prefix must refer to exactly one rule, so that
+/// a short name does not affect more rules than intended.
+pub fn resolve_rule(rule: &str) -> Result<&'static RuleInfo, String> {
+    if let Some(info) = RULES
+        .iter()
+        .find(|info| info.id.eq_ignore_ascii_case(rule) || info.name == rule)
+    {
+        return Ok(info);
+    }
+
+    let matching = RULES
+        .iter()
+        .filter(|info| info.name.starts_with(rule) && info.name[rule.len()..].starts_with('-'))
+        .collect::<Vec<&&RuleInfo>>();
+
+    match matching.len() {
+        0 => Err(format!("Unknown rule {}", rule)),
+        1 => Ok(matching[0]),
+        _ => Err(format!(
+            "Rule {} is ambiguous, it can be any of {}",
+            rule,
+            matching
+                .iter()
+                .map(|info| info.name)
+                .collect::<Vec<&str>>()
+                .join(", ")
+        )),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LogItemTypeLevel {
+    Error,
+    Warning,
+    Info,
+}
+
+impl fmt::Display for LogItemTypeLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LogItemTypeLevel::Error => write!(f, "{}", Paint::red("Error")),
+            LogItemTypeLevel::Warning => write!(f, "{}", Paint::yellow("Warning")),
+            LogItemTypeLevel::Info => write!(f, "{}", Paint::blue("Info")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum LogItemType<'a> {
+    /// When there is used a control sequence which is undefined.
+    UndefinedControlSequence(&'a str),
+
+    /// When there is missing starting brace for a command.
+    TooManyEndingBraces(&'a str),
+
+    /// When a string, which is valid only in math mode, is used outside math environments.
+    NotInMathMode(&'a str),
+
+    /// When there is missing ending brace for a command.
+    RunawayArgument(&'a str),
+
+    /// When a line cannot be stretched to fit.
+    UnderfullHBox(&'a str, usize),
+
+    /// When a line overflows maximum width.
+    OverfullHBox(&'a str),
+
+    /// When a file cannot be found. The kind of the file (class, package, graphic, input,
+    /// bibliography or font) is determined from its extension or from the command which requested
+    /// it.
+    MissingFile(&'a str, FileKind),
+
+    /// When an image with unsupported extension is included.
+    UnknownGraphicsExtension(&'a str),
+
+    /// When an invalid option is passed into a package.
+    InvalidOption(&'a str, &'a str),
+
+    /// When too many &'s are in a row of a table, array or eqnarray.
+    ExtraAlignmentToCR(&'a str, &'a str),
+
+    /// When a group is ended by something else than a closing brace, typically in math mode.
+    MissingEndingBrace(&'a str),
+
+    /// When TeX expects a group to start, but there is no opening brace.
+    MissingStartingBrace(&'a str),
+
+    /// When a closing brace is found inside an environment or other group which was not started
+    /// with a brace.
+    ExtraGroupEnd(&'a str),
+
+    /// When a command which ends or continues a construct (like `\fi`, `\else` or `\endcsname`) is
+    /// used without the corresponding beginning.
+    UnmatchedCommand(&'a str, &'a str),
+
+    /// When a conditional is not terminated with `\fi` before the end of the file.
+    IncompleteConditional(&'a str),
+
+    /// When the document ends while some groups are still open. This is reported only by engines
+    /// which do not list the open groups (see `UnclosedGroup`).
+    EndInsideGroup(usize),
+
+    /// When a group is still open at the end of the document. The type of the group, its level and
+    /// the string which started it are reported.
+    UnclosedGroup(&'a str, usize, &'a str),
+
+    /// When a conditional is still open at the end of the document.
+    UnclosedConditional(&'a str),
+
+    /// When a number is expected, but something else is found.
+    MissingNumber(&'a str),
+
+    /// When a length is given with an unknown unit or without any unit.
+    IllegalUnit(&'a str),
+
+    /// When a length is expected, but neither number nor unit is found. TeX reports it as missing
+    /// number immediately followed by illegal unit of measure, which is usually caused by a mistyped
+    /// length macro.
+    MissingDimension(&'a str),
+
+    /// When a length exceeds the maximum which TeX can handle (16383.99999pt).
+    DimensionTooLarge(&'a str),
+
+    /// When a result of an arithmetic operation cannot be represented, for example on division by
+    /// zero.
+    ArithmeticOverflow(&'a str),
+
+    /// When a number exceeds the maximum which TeX can handle (2147483647).
+    NumberTooBig(&'a str),
+
+    /// When a command is defined with `\newcommand` (or similar), but it already exists.
+    CommandAlreadyDefined(&'a str),
+
+    /// When a command definition uses a parameter with higher number than the number of declared
+    /// parameters.
+    IllegalParameterNumber(&'a str, &'a str),
+
+    /// When the macro parameter character `#` is used outside a command definition.
+    MisplacedParameterCharacter(&'a str, &'a str),
+
+    /// When a command defined with delimited parameters is used without the delimiters.
+    DefinitionMismatch(&'a str, &'a str),
+
+    /// When an argument of a command contains unbalanced closing brace.
+    ArgumentExtraBrace(&'a str, &'a str),
+
+    /// When a command which is allowed only in preamble is used in the document body.
+    PreambleOnly(&'a str),
+
+    /// When a requested font shape (combination of encoding, family, series and shape) does not
+    /// exist and is substituted by another one.
+    FontShapeUndefined(&'a str, &'a str),
+
+    /// When some font shapes were substituted. This is the summary at the end of the log, the
+    /// particular shapes are reported by `FontShapeUndefined`.
+    FontShapesSubstituted,
+
+    /// When a font does not contain some characters. These characters are silently left out from
+    /// the output. All missing characters of a font are reported together.
+    MissingCharacters(&'a str, Vec<&'a str>),
+
+    /// When a font cannot be loaded, usually because it is not installed.
+    FontNotLoadable(&'a str, &'a str),
+
+    /// When a Unicode character which LaTeX does not know how to typeset is used. Replacement is
+    /// suggested for common characters.
+    UnicodeCharacterNotSetUp(&'a str, &'a str, &'a str),
+
+    /// When the source file is not valid UTF-8, usually because it is saved in a different
+    /// encoding.
+    InvalidUtf8(&'a str, &'a str),
+
+    /// When a line contains a character which TeX considers as invalid (category code 15).
+    InvalidCharacter(&'a str),
+
+    /// When a character is not defined in 8-bit input encoding selected by inputenc.
+    UndefinedKeyboardCharacter(&'a str, &'a str),
+
+    /// When a float placement specifier is too restrictive and LaTeX relaxes it.
+    FloatSpecifierChanged(&'a str, &'a str),
+
+    /// When a float is larger than the page. The amount of overflow is reported.
+    FloatTooLarge(&'a str),
+
+    /// When there are too many floats waiting to be placed (LaTeX can hold only 18 of them).
+    TooManyFloats(&'a str),
+
+    /// When a margin note is moved down to avoid overlapping with the previous one. The page of
+    /// the margin note is reported.
+    MarginparMoved(usize),
+
+    /// When a float or a margin note is used where it is not allowed, for example inside a box or
+    /// another float.
+    NotInOuterParMode(&'a str),
+
+    /// When a package is loaded more than once with different options. The first load wins and
+    /// the options of the other loads are ignored. Both option lists are reported.
+    OptionClash(&'a str, &'a str, &'a str),
+
+    /// When a package reports that it was loaded in a wrong order with respect to another package.
+    WrongLoadingOrder(&'a str, &'a str),
+
+    /// When the document class is declared more than once.
+    TwoDocumentClasses,
+
+    /// When a token which cannot be represented in PDF strings (bookmarks, document properties) is
+    /// removed by hyperref.
+    PdfStringTokenRemoved(&'a str),
+
+    /// When a sectioning level is skipped, so the bookmark levels differ by more than one.
+    BookmarkLevelDifference(usize),
+
+    /// When two link destinations have the same name, typically when a counter (like page) is reset
+    /// and then numbered the same way again.
+    DuplicateDestination(&'a str),
+
+    /// When the engine itself (pdfTeX, XeTeX's xdvipdfmx or LuaTeX) reports an error, usually
+    /// related to links, image inclusion, fonts or PDF output. The engine, the area, the file
+    /// named in the message and the message itself are reported.
+    EngineError(&'a str, EngineMessageKind, &'a str, &'a str),
+
+    /// When the engine itself (pdfTeX, XeTeX's xdvipdfmx or LuaTeX) reports a warning. See
+    /// `EngineError`.
+    EngineWarning(&'a str, EngineMessageKind, &'a str, &'a str),
+
+    /// When a Lua code executed by LuaTeX (in `\directlua` or in a package like luaotfload)
+    /// raises an error. The Lua chunk name, the line in the chunk, the message and the frames of
+    /// stack traceback are reported.
+    LuaError(&'a str, usize, &'a str, Vec<&'a str>),
+
+    /// When a package written in LaTeX3 (expl3) reports an error. These errors are spread over
+    /// multiple lines prefixed with the module name and they have the help text in a box after the
+    /// location. The module, the message and the help text (if present) are reported.
+    Expl3Error(&'a str, &'a str, &'a str),
+
+    /// When an unknown key is used in options of TikZ, pgfplots or other package using pgfkeys.
+    /// The key path is split into the family and the key name and a similar known key is suggested
+    /// if there is one.
+    UnknownKey(&'a str, &'a str, &'a str),
+
+    /// When TikZ cannot parse a path, usually because of a missing semicolon at its end.
+    TikzPathGaveUp(&'a str),
+
+    /// When pgfplots reports an error.
+    PgfplotsError(&'a str),
+
+    /// When pgfplots runs in backwards compatibility mode, because the compatibility level is not
+    /// set. The suggested compatibility level is reported.
+    PgfplotsCompatibilityMode(&'a str),
+
+    /// When the bibliography has to be generated by Biber or BibTeX and LaTeX has to be run
+    /// again. The tool and the file to run it on are reported.
+    RerunBibliographyTool(&'a str, &'a str),
+
+    /// When biblatex reports a problem with encoding of bibliography data.
+    BibliographyDataEncoding(&'a str, &'a str),
+
+    /// When a citation refers to a key which is not in the bibliography.
+    UndefinedCitation(&'a str, usize),
+
+    /// When a bibliography entry with the same key is defined more than once. The key is reported
+    /// if it is known, natbib reports it in a separate warning.
+    MultiplyDefinedCitation(&'a str),
+
+    /// When a package cannot be used together with a bibliography package.
+    IncompatiblePackage(&'a str, &'a str),
+
+    /// When the bibliography environment has no entries.
+    EmptyBibliography,
+
+    /// When a length of the page layout is too small for the content, for example the height of a
+    /// fancyhdr header. The length, its current value and the required value are reported.
+    LayoutLengthTooSmall(&'a str, &'a str, &'a str),
+
+    /// When the geometry package is given more dimensions than it can satisfy in one direction.
+    /// The direction and the ignored dimension with its value are reported.
+    GeometryOverSpecification(&'a str, &'a str, &'a str),
+
+    /// When microtype settings refer to a character which is not in the font encoding.
+    MicrotypeUnknownSlot(&'a str, &'a str),
+
+    /// When KOMA-Script typearea computes a line width very different from the expected one.
+    BadTypeArea(&'a str),
+
+    /// When hyphenation patterns of a language are not available in the format, so the text in
+    /// that language is hyphenated incorrectly or not at all.
+    MissingHyphenationPatterns(&'a str),
+
+    /// When babel or polyglossia does not know a language, which means the language definition
+    /// file is not installed or the name is misspelled. The package and the language are reported.
+    UnknownLanguage(&'a str, &'a str),
+
+    /// When TeX runs out of some of its memory. The capacity, its limit and the macro which is
+    /// suspected of infinite recursion (if any) are reported.
+    CapacityExceeded(&'a str, &'a str, &'a str),
+
+    /// When `\verb` is not ended on the same line, usually because the delimiter is missing.
+    VerbEndedByEndOfLine,
+
+    /// When `\verb` is used in an argument of another command.
+    VerbInArgument,
+
+    /// When a fragile command is used in a moving argument (such as a section title or a caption)
+    /// without `\protect`. The fragile command (if it is known) and the internal command which
+    /// processed the moving argument are reported.
+    FragileCommand(&'a str, &'a str),
+
+    /// When an alignment character or command is used outside of a table or at a wrong place in
+    /// it. The character or command (`&`, `\noalign` or `\omit`) and the row are reported.
+    MisplacedAlignment(&'a str, &'a str),
+
+    /// When a column specification of a table contains an unknown token. The token (if reported)
+    /// and the column specification are reported.
+    IllegalPreambleToken(&'a str, &'a str),
+
+    /// When `\maketitle` is used without the author or the title of the document. Missing title is
+    /// an error, missing author is reported only for information.
+    MissingTitleData(&'a str),
+
+    /// When hyperref needs another run to get some PDF data right.
+    RerunForPdfData(&'a str),
+
+    /// When global options given to the document class are not used by the class nor by any
+    /// package, which usually means they are misspelled.
+    UnusedGlobalOptions(&'a str),
+
+    /// When a file is written by the filecontents environment.
+    FileWritten(&'a str),
+
+    /// When a document class or package provides a different name than the requested one. The
+    /// kind, the requested name and the provided name are reported.
+    ProvidedNameMismatch(&'a str, &'a str, &'a str),
+}
+
+impl<'a> LogItemType<'a> {
+    /// Returns the rule which found the item.
+    pub fn get_rule(&self) -> &'static RuleInfo {
+        match *self {
+            LogItemType::UndefinedControlSequence(_) => &UNDEFINED_CONTROL_SEQUENCE,
+            LogItemType::TooManyEndingBraces(_) => &TOO_MANY_ENDING_BRACES,
+            LogItemType::NotInMathMode(_) => &NOT_IN_MATH_MODE,
+            LogItemType::RunawayArgument(_) => &RUNAWAY_ARGUMENT,
+            LogItemType::UnderfullHBox(_, _) => &UNDERFULL_HBOX,
+            LogItemType::OverfullHBox(_) => &OVERFULL_HBOX,
+            LogItemType::MissingFile(_, _) => &MISSING_FILE,
+            LogItemType::UnknownGraphicsExtension(_) => &UNKNOWN_GRAPHICS_EXTENSION,
+            LogItemType::InvalidOption(_, _) => &INVALID_OPTION,
+            LogItemType::ExtraAlignmentToCR(_, _) => &EXTRA_ALIGNMENT_TO_CR,
+            LogItemType::MissingEndingBrace(_) => &MISSING_ENDING_BRACE,
+            LogItemType::MissingStartingBrace(_) => &MISSING_STARTING_BRACE,
+            LogItemType::ExtraGroupEnd(_) => &EXTRA_GROUP_END,
+            LogItemType::UnmatchedCommand(_, _) => &UNMATCHED_COMMAND,
+            LogItemType::IncompleteConditional(_) => &INCOMPLETE_CONDITIONAL,
+            LogItemType::EndInsideGroup(_) => &END_INSIDE_GROUP,
+            LogItemType::UnclosedGroup(_, _, _) => &UNCLOSED_GROUP,
+            LogItemType::UnclosedConditional(_) => &UNCLOSED_CONDITIONAL,
+            LogItemType::MissingNumber(_) => &MISSING_NUMBER,
+            LogItemType::IllegalUnit(_) => &ILLEGAL_UNIT,
+            LogItemType::MissingDimension(_) => &MISSING_DIMENSION,
+            LogItemType::DimensionTooLarge(_) => &DIMENSION_TOO_LARGE,
+            LogItemType::ArithmeticOverflow(_) => &ARITHMETIC_OVERFLOW,
+            LogItemType::NumberTooBig(_) => &NUMBER_TOO_BIG,
+            LogItemType::CommandAlreadyDefined(_) => &COMMAND_ALREADY_DEFINED,
+            LogItemType::IllegalParameterNumber(_, _) => &ILLEGAL_PARAMETER_NUMBER,
+            LogItemType::MisplacedParameterCharacter(_, _) => &MISPLACED_PARAMETER_CHARACTER,
+            LogItemType::DefinitionMismatch(_, _) => &DEFINITION_MISMATCH,
+            LogItemType::ArgumentExtraBrace(_, _) => &ARGUMENT_EXTRA_BRACE,
+            LogItemType::PreambleOnly(_) => &PREAMBLE_ONLY,
+            LogItemType::FontShapeUndefined(_, _) => &FONT_SHAPE_UNDEFINED,
+            LogItemType::FontShapesSubstituted => &FONT_SHAPES_SUBSTITUTED,
+            LogItemType::MissingCharacters(_, _) => &MISSING_CHARACTERS,
+            LogItemType::FontNotLoadable(_, _) => &FONT_NOT_LOADABLE,
+            LogItemType::UnicodeCharacterNotSetUp(_, _, _) => &UNICODE_CHARACTER_NOT_SET_UP,
+            LogItemType::InvalidUtf8(_, _) => &INVALID_UTF8,
+            LogItemType::InvalidCharacter(_) => &INVALID_CHARACTER,
+            LogItemType::UndefinedKeyboardCharacter(_, _) => &UNDEFINED_KEYBOARD_CHARACTER,
+            LogItemType::FloatSpecifierChanged(_, _) => &FLOAT_SPECIFIER_CHANGED,
+            LogItemType::FloatTooLarge(_) => &FLOAT_TOO_LARGE,
+            LogItemType::TooManyFloats(_) => &TOO_MANY_FLOATS,
+            LogItemType::MarginparMoved(_) => &MARGINPAR_MOVED,
+            LogItemType::NotInOuterParMode(_) => &NOT_IN_OUTER_PAR_MODE,
+            LogItemType::OptionClash(_, _, _) => &OPTION_CLASH,
+            LogItemType::WrongLoadingOrder(_, _) => &WRONG_LOADING_ORDER,
+            LogItemType::TwoDocumentClasses => &TWO_DOCUMENT_CLASSES,
+            LogItemType::PdfStringTokenRemoved(_) => &PDF_STRING_TOKEN_REMOVED,
+            LogItemType::BookmarkLevelDifference(_) => &BOOKMARK_LEVEL_DIFFERENCE,
+            LogItemType::DuplicateDestination(_) => &DUPLICATE_DESTINATION,
+            LogItemType::EngineError(_, _, _, _) => &ENGINE_ERROR,
+            LogItemType::EngineWarning(_, _, _, _) => &ENGINE_WARNING,
+            LogItemType::LuaError(_, _, _, _) => &LUA_ERROR,
+            LogItemType::Expl3Error(_, _, _) => &EXPL3_ERROR,
+            LogItemType::UnknownKey(_, _, _) => &UNKNOWN_KEY,
+            LogItemType::TikzPathGaveUp(_) => &TIKZ_PATH_GAVE_UP,
+            LogItemType::PgfplotsError(_) => &PGFPLOTS_ERROR,
+            LogItemType::PgfplotsCompatibilityMode(_) => &PGFPLOTS_COMPATIBILITY_MODE,
+            LogItemType::RerunBibliographyTool(_, _) => &RERUN_BIBLIOGRAPHY_TOOL,
+            LogItemType::BibliographyDataEncoding(_, _) => &BIBLIOGRAPHY_DATA_ENCODING,
+            LogItemType::UndefinedCitation(_, _) => &UNDEFINED_CITATION,
+            LogItemType::MultiplyDefinedCitation(_) => &MULTIPLY_DEFINED_CITATION,
+            LogItemType::IncompatiblePackage(_, _) => &INCOMPATIBLE_PACKAGE,
+            LogItemType::EmptyBibliography => &EMPTY_BIBLIOGRAPHY,
+            LogItemType::LayoutLengthTooSmall(_, _, _) => &LAYOUT_LENGTH_TOO_SMALL,
+            LogItemType::GeometryOverSpecification(_, _, _) => &GEOMETRY_OVER_SPECIFICATION,
+            LogItemType::MicrotypeUnknownSlot(_, _) => &MICROTYPE_UNKNOWN_SLOT,
+            LogItemType::BadTypeArea(_) => &BAD_TYPE_AREA,
+            LogItemType::MissingHyphenationPatterns(_) => &MISSING_HYPHENATION_PATTERNS,
+            LogItemType::UnknownLanguage(_, _) => &UNKNOWN_LANGUAGE,
+            LogItemType::CapacityExceeded(_, _, _) => &CAPACITY_EXCEEDED,
+            LogItemType::VerbEndedByEndOfLine => &VERB_ENDED_BY_END_OF_LINE,
+            LogItemType::VerbInArgument => &VERB_IN_ARGUMENT,
+            LogItemType::FragileCommand(_, _) => &FRAGILE_COMMAND,
+            LogItemType::MisplacedAlignment(_, _) => &MISPLACED_ALIGNMENT,
+            LogItemType::IllegalPreambleToken(_, _) => &ILLEGAL_PREAMBLE_TOKEN,
+            LogItemType::MissingTitleData(_) => &MISSING_TITLE_DATA,
+            LogItemType::RerunForPdfData(_) => &RERUN_FOR_PDF_DATA,
+            LogItemType::UnusedGlobalOptions(_) => &UNUSED_GLOBAL_OPTIONS,
+            LogItemType::FileWritten(_) => &FILE_WRITTEN,
+            LogItemType::ProvidedNameMismatch(_, _, _) => &PROVIDED_NAME_MISMATCH,
+        }
+    }
+
+    /// Returns the stable identifier of the rule which found the item.
+    pub fn get_id(&self) -> &'static str {
+        self.get_rule().id
+    }
+
+    /// Returns the identifier of the rule and the fields of the item which identify it regardless
+    /// of its place in the document (that is without line and page numbers), separated by a fixed
+    /// character.
+    pub fn get_key(&self) -> String {
+        let fields = match *self {
+            LogItemType::UndefinedControlSequence(first)
+            | LogItemType::TooManyEndingBraces(first)
+            | LogItemType::NotInMathMode(first)
+            | LogItemType::RunawayArgument(first)
+            | LogItemType::OverfullHBox(first)
+            | LogItemType::UnknownGraphicsExtension(first)
+            | LogItemType::MissingEndingBrace(first)
+            | LogItemType::MissingStartingBrace(first)
+            | LogItemType::ExtraGroupEnd(first)
+            | LogItemType::IncompleteConditional(first)
+            | LogItemType::UnclosedConditional(first)
+            | LogItemType::MissingNumber(first)
+            | LogItemType::IllegalUnit(first)
+            | LogItemType::MissingDimension(first)
+            | LogItemType::DimensionTooLarge(first)
+            | LogItemType::ArithmeticOverflow(first)
+            | LogItemType::NumberTooBig(first)
+            | LogItemType::CommandAlreadyDefined(first)
+            | LogItemType::PreambleOnly(first)
+            | LogItemType::InvalidCharacter(first)
+            | LogItemType::FloatTooLarge(first)
+            | LogItemType::TooManyFloats(first)
+            | LogItemType::NotInOuterParMode(first)
+            | LogItemType::PdfStringTokenRemoved(first)
+            | LogItemType::DuplicateDestination(first)
+            | LogItemType::TikzPathGaveUp(first)
+            | LogItemType::PgfplotsError(first)
+            | LogItemType::PgfplotsCompatibilityMode(first)
+            | LogItemType::MultiplyDefinedCitation(first)
+            | LogItemType::BadTypeArea(first)
+            | LogItemType::MissingHyphenationPatterns(first)
+            | LogItemType::MissingTitleData(first)
+            | LogItemType::RerunForPdfData(first)
+            | LogItemType::UnusedGlobalOptions(first)
+            | LogItemType::FileWritten(first) => vec![first.to_owned()],
+            LogItemType::InvalidOption(first, second)
+            | LogItemType::ExtraAlignmentToCR(first, second)
+            | LogItemType::UnmatchedCommand(first, second)
+            | LogItemType::IllegalParameterNumber(first, second)
+            | LogItemType::MisplacedParameterCharacter(first, second)
+            | LogItemType::DefinitionMismatch(first, second)
+            | LogItemType::ArgumentExtraBrace(first, second)
+            | LogItemType::FontShapeUndefined(first, second)
+            | LogItemType::FontNotLoadable(first, second)
+            | LogItemType::InvalidUtf8(first, second)
+            | LogItemType::UndefinedKeyboardCharacter(first, second)
+            | LogItemType::FloatSpecifierChanged(first, second)
+            | LogItemType::WrongLoadingOrder(first, second)
+            | LogItemType::RerunBibliographyTool(first, second)
+            | LogItemType::BibliographyDataEncoding(first, second)
+            | LogItemType::IncompatiblePackage(first, second)
+            | LogItemType::MicrotypeUnknownSlot(first, second)
+            | LogItemType::UnknownLanguage(first, second)
+            | LogItemType::FragileCommand(first, second)
+            | LogItemType::MisplacedAlignment(first, second)
+            | LogItemType::IllegalPreambleToken(first, second) => {
+                vec![first.to_owned(), second.to_owned()]
+            }
+            LogItemType::UnicodeCharacterNotSetUp(first, second, third)
+            | LogItemType::OptionClash(first, second, third)
+            | LogItemType::Expl3Error(first, second, third)
+            | LogItemType::UnknownKey(first, second, third)
+            | LogItemType::LayoutLengthTooSmall(first, second, third)
+            | LogItemType::GeometryOverSpecification(first, second, third)
+            | LogItemType::CapacityExceeded(first, second, third)
+            | LogItemType::ProvidedNameMismatch(first, second, third) => {
+                vec![first.to_owned(), second.to_owned(), third.to_owned()]
+            }
+            LogItemType::UnderfullHBox(input, badness) => {
+                vec![input.to_owned(), badness.to_string()]
+            }
+            LogItemType::EndInsideGroup(level) => vec![level.to_string()],
+            LogItemType::UnclosedGroup(group, _, context) => {
+                vec![group.to_owned(), context.to_owned()]
+            }
+            LogItemType::MissingCharacters(font, ref characters) => {
+                vec![font.to_owned(), characters.join("\n")]
+            }
+            LogItemType::MarginparMoved(_) => Vec::new(),
+            LogItemType::BookmarkLevelDifference(level) => vec![level.to_string()],
+            LogItemType::LuaError(chunk, _, message, ref traceback) => {
+                vec![chunk.to_owned(), message.to_owned(), traceback.join("\n")]
+            }
+            LogItemType::UndefinedCitation(key, _) => vec![key.to_owned()],
+            // the kinds of files and engine messages are derived from the messages
+            LogItemType::MissingFile(file, _) => vec![file.to_owned()],
+            LogItemType::EngineError(engine, _, file, message)
+            | LogItemType::EngineWarning(engine, _, file, message) => {
+                vec![engine.to_owned(), file.to_owned(), message.to_owned()]
+            }
+            LogItemType::FontShapesSubstituted
+            | LogItemType::TwoDocumentClasses
+            | LogItemType::EmptyBibliography
+            | LogItemType::VerbEndedByEndOfLine
+            | LogItemType::VerbInArgument => Vec::new(),
+        };
+
+        // the unit separator does not occur in log files
+        let mut key = String::from(self.get_id());
+        for field in fields {
+            key.push('\u{1f}');
+            key.push_str(&field);
+        }
+        key
+    }
+
+    fn get_level(&self) -> LogItemTypeLevel {
+        match *self {
+            LogItemType::UndefinedControlSequence(_)
+            | LogItemType::TooManyEndingBraces(_)
+            | LogItemType::NotInMathMode(_)
+            | LogItemType::RunawayArgument(_)
+            | LogItemType::UnknownGraphicsExtension(_)
+            | LogItemType::InvalidOption(_, _)
+            | LogItemType::ExtraAlignmentToCR(_, _)
+            | LogItemType::MissingEndingBrace(_)
+            | LogItemType::MissingStartingBrace(_)
+            | LogItemType::ExtraGroupEnd(_)
+            | LogItemType::UnmatchedCommand(_, _)
+            | LogItemType::IncompleteConditional(_)
+            | LogItemType::MissingNumber(_)
+            | LogItemType::IllegalUnit(_)
+            | LogItemType::MissingDimension(_)
+            | LogItemType::DimensionTooLarge(_)
+            | LogItemType::ArithmeticOverflow(_)
+            | LogItemType::NumberTooBig(_)
+            | LogItemType::CommandAlreadyDefined(_)
+            | LogItemType::IllegalParameterNumber(_, _)
+            | LogItemType::MisplacedParameterCharacter(_, _)
+            | LogItemType::DefinitionMismatch(_, _)
+            | LogItemType::ArgumentExtraBrace(_, _)
+            | LogItemType::PreambleOnly(_)
+            | LogItemType::FontNotLoadable(_, _)
+            | LogItemType::UnicodeCharacterNotSetUp(_, _, _)
+            | LogItemType::InvalidUtf8(_, _)
+            | LogItemType::InvalidCharacter(_)
+            | LogItemType::UndefinedKeyboardCharacter(_, _)
+            | LogItemType::TooManyFloats(_)
+            | LogItemType::NotInOuterParMode(_)
+            | LogItemType::OptionClash(_, _, _)
+            | LogItemType::TwoDocumentClasses
+            | LogItemType::EngineError(_, _, _, _)
+            | LogItemType::LuaError(_, _, _, _)
+            | LogItemType::Expl3Error(_, _, _)
+            | LogItemType::UnknownKey(_, _, _)
+            | LogItemType::TikzPathGaveUp(_)
+            | LogItemType::PgfplotsError(_)
+            | LogItemType::IncompatiblePackage(_, _)
+            | LogItemType::UnknownLanguage(_, _)
+            | LogItemType::CapacityExceeded(_, _, _)
+            | LogItemType::VerbEndedByEndOfLine
+            | LogItemType::VerbInArgument
+            | LogItemType::FragileCommand(_, _)
+            | LogItemType::MisplacedAlignment(_, _)
+            | LogItemType::IllegalPreambleToken(_, _) => LogItemTypeLevel::Error,
+            LogItemType::MissingFile(_, ref kind) => if *kind == FileKind::Bibliography {
+                LogItemTypeLevel::Warning
+            } else {
+                LogItemTypeLevel::Error
+            },
+            // LaTeX reports missing title as an error
+            LogItemType::MissingTitleData(data) => if data == "title" {
+                LogItemTypeLevel::Error
+            } else {
+                LogItemTypeLevel::Info
+            },
+            LogItemType::UnderfullHBox(_, _)
+            | LogItemType::OverfullHBox(_)
+            | LogItemType::EndInsideGroup(_)
+            | LogItemType::UnclosedGroup(_, _, _)
+            | LogItemType::UnclosedConditional(_)
+            | LogItemType::FontShapeUndefined(_, _)
+            | LogItemType::FontShapesSubstituted
+            | LogItemType::MissingCharacters(_, _)
+            | LogItemType::FloatSpecifierChanged(_, _)
+            | LogItemType::FloatTooLarge(_)
+            | LogItemType::MarginparMoved(_)
+            | LogItemType::WrongLoadingOrder(_, _)
+            | LogItemType::PdfStringTokenRemoved(_)
+            | LogItemType::BookmarkLevelDifference(_)
+            | LogItemType::DuplicateDestination(_)
+            | LogItemType::EngineWarning(_, _, _, _)
+            | LogItemType::PgfplotsCompatibilityMode(_)
+            | LogItemType::RerunBibliographyTool(_, _)
+            | LogItemType::BibliographyDataEncoding(_, _)
+            | LogItemType::UndefinedCitation(_, _)
+            | LogItemType::MultiplyDefinedCitation(_)
+            | LogItemType::EmptyBibliography
+            | LogItemType::LayoutLengthTooSmall(_, _, _)
+            | LogItemType::GeometryOverSpecification(_, _, _)
+            | LogItemType::MicrotypeUnknownSlot(_, _)
+            | LogItemType::BadTypeArea(_)
+            | LogItemType::MissingHyphenationPatterns(_) => LogItemTypeLevel::Warning,
+            LogItemType::RerunForPdfData(_)
+            | LogItemType::UnusedGlobalOptions(_)
+            | LogItemType::FileWritten(_)
+            | LogItemType::ProvidedNameMismatch(_, _, _) => LogItemTypeLevel::Info,
+        }
+    }
+}
+
+impl<'a> LogItemType<'a> {
+    /// Returns true if the item is related to the bibliography rather than to the source.
+    pub fn is_bibliography(&self) -> bool {
+        match *self {
+            LogItemType::MissingFile(_, ref kind) => *kind == FileKind::Bibliography,
+            LogItemType::RerunBibliographyTool(_, _)
+            | LogItemType::BibliographyDataEncoding(_, _)
+            | LogItemType::UndefinedCitation(_, _)
+            | LogItemType::MultiplyDefinedCitation(_)
+            | LogItemType::IncompatiblePackage(_, _)
+            | LogItemType::EmptyBibliography => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct LogItem<'a> {
+    pub item_type: LogItemType<'a>,
+    pub location: Location,
+    pub level: LogItemTypeLevel,
+}
+
+impl<'a> LogItem<'a> {
+    fn new(item_type: LogItemType<'a>, location: Location) -> Self {
+        let level = item_type.get_level();
+
+        Self {
+            item_type,
+            location,
+            level,
+        }
+    }
+
+    pub fn rules() -> Vec<&'a Rule<'a>> {
+        vec![
+            &UndefinedControlSequence,
+            &TooManyEndingBraces,
+            &NotInMathMode,
+            &RunawayArgument,
+            &RunawayArgument2,
+            &UnderfullHBox,
+            &OverfullHBox,
+            &MissingFile,
+            &CannotFindFile,
+            &NoBibliographyFile,
+            &UnknownGraphicsExtension,
+            &InvalidOption,
+            &ExtraAlignmentToCR,
+            &MissingEndingBrace,
+            &MissingStartingBrace,
+            &ExtraGroupEnd,
+            &UnmatchedCommand,
+            &IncompleteConditional,
+            &EndInsideGroup,
+            &UnclosedGroup,
+            &UnclosedConditional,
+            &MissingNumber,
+            &IllegalUnit,
+            &DimensionTooLarge,
+            &ArithmeticOverflow,
+            &NumberTooBig,
+            &CommandAlreadyDefined,
+            &IllegalParameterNumber,
+            &MisplacedParameterCharacter,
+            &DefinitionMismatch,
+            &ArgumentExtraBrace,
+            &PreambleOnly,
+            &FontShapeUndefined,
+            &FontShapesSubstituted,
+            &MissingCharacter,
+            &FontNotLoadable,
+            &UnicodeCharacterNotSetUp,
+            &InvalidUtf8,
+            &InvalidCharacter,
+            &UndefinedKeyboardCharacter,
+            &FloatSpecifierChanged,
+            &FloatTooLarge,
+            &TooManyFloats,
+            &MarginparMoved,
+            &NotInOuterParMode,
+            &OptionClash,
+            &WrongLoadingOrder,
+            &TwoDocumentClasses,
+            &PdfStringTokenRemoved,
+            &BookmarkLevelDifference,
+            &DuplicateDestination,
+            &PdfTeXError,
+            &PdfTeXWarning,
+            &XdvipdfmxMessage,
+            &LuaTeXBackendMessage,
+            &LuaError,
+            &Expl3Error,
+            &UnknownKey,
+            &TikzPathGaveUp,
+            &PgfplotsError,
+            &PgfplotsCompatibilityMode,
+            &RerunBibliographyTool,
+            &BibliographyDataEncoding,
+            &UndefinedCitation,
+            &MultiplyDefinedCitation,
+            &IncompatiblePackage,
+            &EmptyBibliography,
+            &LayoutLengthTooSmall,
+            &GeometryOverSpecification,
+            &MicrotypeUnknownSlot,
+            &BadTypeArea,
+            &MissingHyphenationPatterns,
+            &UnknownLanguage,
+            &CapacityExceeded,
+            &VerbEndedByEndOfLine,
+            &VerbInArgument,
+            &MisplacedAlignment,
+            &IllegalPreambleToken,
+            &IllegalArrayCharacter,
+            &MissingTitleData,
+            &RerunForPdfData,
+            &UnusedGlobalOptions,
+            &FileWritten,
+            &ProvidedNameMismatch,
+        ]
+    }
+}
+
+impl<'a> fmt::Display for LogItem<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let level = self.level;
+        let id = Paint::white(format!("[{}]", self.item_type.get_id())).dimmed();
+
+        match self.location {
+            Location::Line(line) => write!(
+                f,
+                "{}{} {} {}: ",
+                level,
+                id,
+                Paint::white("on line").italic(),
+                Paint::white(line).bold()
+            )?,
+            Location::End => write!(
+                f,
+                "{}{} {}: ",
+                level,
+                id,
+                Paint::white("at the end").italic()
+            )?,
+            Location::None => write!(f, "{}{}: ", level, id)?,
+        }
+
+        match self.item_type {
+            LogItemType::UndefinedControlSequence(command) => {
+                write!(f, "Unknown command {}.", Paint::cyan(command))
+            }
+            LogItemType::TooManyEndingBraces(context) => write!(
+                f,
+                "Number of curly braces near {} does not match.",
+                Paint::white(context).bold()
+            ),
+            LogItemType::NotInMathMode(input) => write!(
+                f,
+                "String {} is valid only in math mode.",
+                Paint::white(input).bold()
+            ),
+            LogItemType::RunawayArgument(command) => write!(
+                f,
+                "Command {} was not properly ended with curly brace.",
+                Paint::cyan(command),
+            ),
+            LogItemType::UnderfullHBox(input, badness) => if input.is_empty() {
+                write!(
+                    f,
+                    "Line cannot be stretch enough. The problem is {}.",
+                    if badness < 2000 {
+                        "ignorable"
+                    } else if badness < 6000 {
+                        "not as bad"
+                    } else {
+                        "very bad"
+                    }
+                )
+            } else {
+                write!(
+                    f,
+                    "Due to {} the line cannot be stretch enough. The problem is {}.",
+                    input,
+                    if badness < 2000 {
+                        "ignorable"
+                    } else if badness < 6000 {
+                        "not as bad"
+                    } else {
+                        "very bad"
+                    }
+                )
+            },
+            LogItemType::OverfullHBox(input) => write!(
+                f,
+                "Text after {} (displayed hyphenated) overflows the line end.",
+                Paint::white(input.replace("\n", "").trim()).bold()
+            ),
+            LogItemType::MissingFile(filename, ref kind) => {
+                let stem = match filename.rfind('.') {
+                    Some(index) => &filename[..index],
+                    None => filename,
+                };
+
+                match *kind {
+                    FileKind::Class => write!(f, "Missing document class {}.", Paint::cyan(stem)),
+                    FileKind::Package => write!(f, "Missing package {}.", Paint::cyan(stem)),
+                    FileKind::Graphic => write!(
+                        f,
+                        "Missing image {} (check the path and the extension).",
+                        Paint::cyan(filename)
+                    ),
+                    FileKind::Input => {
+                        write!(f, "Missing included file {}.", Paint::cyan(filename))
+                    }
+                    FileKind::Bibliography => write!(
+                        f,
+                        "Missing bibliography file {} (run BibTeX or Biber first).",
+                        Paint::cyan(filename)
+                    ),
+                    FileKind::Font => write!(f, "Missing font file {}.", Paint::cyan(filename)),
+                    FileKind::Other => write!(f, "Missing file {}.", Paint::cyan(filename)),
+                }
+            }
+            LogItemType::UnknownGraphicsExtension(extension) => write!(
+                f,
+                "Images with extension {} are not supported by this engine.",
+                Paint::cyan(extension)
+            ),
+            LogItemType::InvalidOption(option, package) => write!(
+                f,
+                "Invalid option {} of package {}.",
+                Paint::cyan(option),
+                Paint::white(package).bold()
+            ),
+            LogItemType::ExtraAlignmentToCR("", input) => write!(
+                f,
+                "There are more &'s than should be in a aligned environment (table, etc.) near {}.",
+                Paint::white(input).bold()
+            ),
+            LogItemType::ExtraAlignmentToCR("tabularx", input) => write!(
+                f,
+                "There are more &'s than columns in {} (the line refers to the end of the environment, near {}).",
+                Paint::cyan("tabularx"),
+                Paint::white(input).bold()
+            ),
+            LogItemType::ExtraAlignmentToCR(environment, input) => write!(
+                f,
+                "There are more &'s than columns in {} near {}.",
+                Paint::cyan(environment),
+                Paint::white(input).bold()
+            ),
+            LogItemType::MissingEndingBrace(input) => write!(
+                f,
+                "Group near {} was ended before its closing curly brace.",
+                Paint::white(input).bold()
+            ),
+            LogItemType::MissingStartingBrace(input) => write!(
+                f,
+                "Opening curly brace is expected near {}.",
+                Paint::white(input).bold()
+            ),
+            LogItemType::ExtraGroupEnd(input) => write!(
+                f,
+                "Closing curly brace near {} does not match any opening brace.",
+                Paint::white(input).bold()
+            ),
+            LogItemType::UnmatchedCommand(command, input) => write!(
+                f,
+                "Command {} near {} has no matching beginning.",
+                Paint::cyan(command),
+                Paint::white(input).bold()
+            ),
+            LogItemType::IncompleteConditional(conditional) => write!(
+                f,
+                "Conditional {} is not ended with {}, all text after it was ignored.",
+                Paint::cyan(conditional),
+                Paint::cyan("\\fi")
+            ),
+            LogItemType::EndInsideGroup(level) => write!(
+                f,
+                "Document ended inside {} unclosed group(s).",
+                Paint::white(level).bold()
+            ),
+            LogItemType::UnclosedGroup(group, level, opening) => if opening.is_empty() {
+                write!(
+                    f,
+                    "The {} (level {}) is never closed.",
+                    group,
+                    Paint::white(level).bold()
+                )
+            } else {
+                write!(
+                    f,
+                    "The {} (level {}) started with {} is never closed.",
+                    group,
+                    Paint::white(level).bold(),
+                    Paint::cyan(opening)
+                )
+            },
+            LogItemType::UnclosedConditional(conditional) => write!(
+                f,
+                "Conditional {} is not ended with {}.",
+                Paint::cyan(conditional),
+                Paint::cyan("\\fi")
+            ),
+            LogItemType::MissingNumber(input) => write!(
+                f,
+                "Number is expected near {}, zero was used instead.",
+                Paint::white(input).bold()
+            ),
+            LogItemType::IllegalUnit(input) => write!(
+                f,
+                "Length near {} has missing or unknown unit, pt was used instead.",
+                Paint::white(input).bold()
+            ),
+            LogItemType::MissingDimension(input) => write!(
+                f,
+                "Length is expected near {}, but there is neither number nor unit (mistyped length command?).",
+                Paint::white(input).bold()
+            ),
+            LogItemType::DimensionTooLarge(input) => write!(
+                f,
+                "Length near {} is larger than maximum {}.",
+                Paint::white(input).bold(),
+                Paint::white("16383.99999pt").bold()
+            ),
+            LogItemType::ArithmeticOverflow(input) => write!(
+                f,
+                "Result of arithmetic operation near {} overflows (division by zero?).",
+                Paint::white(input).bold()
+            ),
+            LogItemType::NumberTooBig(input) => write!(
+                f,
+                "Number near {} is larger than maximum {}.",
+                Paint::white(input).bold(),
+                Paint::white("2147483647").bold()
+            ),
+            LogItemType::CommandAlreadyDefined(command) => write!(
+                f,
+                "Command {} is already defined (use {} to change it).",
+                Paint::cyan(command),
+                Paint::cyan("\\renewcommand")
+            ),
+            LogItemType::IllegalParameterNumber(command, input) => write!(
+                f,
+                "Definition of command {} near {} uses more parameters than it declares.",
+                Paint::cyan(command),
+                Paint::white(input).bold()
+            ),
+            LogItemType::MisplacedParameterCharacter(mode, input) => write!(
+                f,
+                "Character # near {} is valid only in command definitions, not in {} mode (use {} to typeset it).",
+                Paint::white(input).bold(),
+                mode,
+                Paint::cyan("\\#")
+            ),
+            LogItemType::DefinitionMismatch(command, input) => write!(
+                f,
+                "Usage of command {} near {} does not match its definition.",
+                Paint::cyan(command),
+                Paint::white(input).bold()
+            ),
+            LogItemType::ArgumentExtraBrace(command, input) => write!(
+                f,
+                "Argument of command {} near {} has an extra closing curly brace.",
+                Paint::cyan(command),
+                Paint::white(input).bold()
+            ),
+            LogItemType::PreambleOnly(command) => write!(
+                f,
+                "Command {} can be used only in preamble.",
+                Paint::cyan(command)
+            ),
+            LogItemType::FontShapeUndefined(shape, substitute) => write!(
+                f,
+                "Font shape {} is not available, {} was used instead.",
+                Paint::cyan(shape),
+                Paint::cyan(substitute)
+            ),
+            LogItemType::FontShapesSubstituted => write!(
+                f,
+                "Some font shapes were not available and were substituted by default ones."
+            ),
+            LogItemType::MissingCharacters(font, ref characters) => {
+                write!(f, "Font {} does not contain characters ", Paint::cyan(font))?;
+
+                for (index, character) in characters.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(
+                        f,
+                        "{} ({})",
+                        Paint::white(character).bold(),
+                        code_point(character)
+                    )?;
+                }
+
+                write!(f, ", they are missing in the output.")
+            }
+            LogItemType::FontNotLoadable(font, reason) => write!(
+                f,
+                "Font {} cannot be loaded ({}), is it installed?",
+                Paint::cyan(font),
+                reason
+            ),
+            LogItemType::UnicodeCharacterNotSetUp(character, code, input) => {
+                let code = match u32::from_str_radix(code, 16) {
+                    Ok(code) => Some(code),
+                    Err(_) => decode_character(character),
+                };
+
+                write!(
+                    f,
+                    "Unicode character {} ({}) near {} is not supported",
+                    Paint::white(character).bold(),
+                    code.map_or(String::from("U+?"), |code| format!("U+{:04X}", code)),
+                    Paint::white(input).bold()
+                )?;
+
+                match code.and_then(unicode_replacement) {
+                    Some(replacement) => write!(f, ", use {} instead.", Paint::cyan(replacement)),
+                    None => write!(
+                        f,
+                        ", define it with {} or use XeLaTeX or LuaLaTeX.",
+                        Paint::cyan("\\DeclareUnicodeCharacter")
+                    ),
+                }
+            }
+            LogItemType::InvalidUtf8(byte, input) => if byte.is_empty() {
+                write!(
+                    f,
+                    "Text near {} is not valid UTF-8, is the file saved in a different encoding?",
+                    Paint::white(input).bold()
+                )
+            } else {
+                write!(
+                    f,
+                    "Text near {} is not valid UTF-8 (byte {}), is the file saved in a different encoding?",
+                    Paint::white(input).bold(),
+                    Paint::white(format!("0x{}", byte)).bold()
+                )
+            },
+            LogItemType::InvalidCharacter(input) => write!(
+                f,
+                "Invalid character near {}, it is probably a control character which got into the source.",
+                Paint::white(input).bold()
+            ),
+            LogItemType::UndefinedKeyboardCharacter(encoding, input) => write!(
+                f,
+                "Character near {} is not defined in input encoding {}, is the file saved in this encoding?",
+                Paint::white(input).bold(),
+                Paint::cyan(encoding)
+            ),
+            LogItemType::FloatSpecifierChanged(from, to) => write!(
+                f,
+                "Float placement {} is too restrictive, {} was used instead.",
+                Paint::cyan(from),
+                Paint::cyan(to)
+            ),
+            LogItemType::FloatTooLarge(overflow) => write!(
+                f,
+                "Float is too large for the page by {}.",
+                Paint::white(overflow).bold()
+            ),
+            LogItemType::TooManyFloats(input) => write!(
+                f,
+                "Too many floats are waiting to be placed near {} (use {} or less restrictive placement).",
+                Paint::white(input).bold(),
+                Paint::cyan("\\clearpage")
+            ),
+            LogItemType::MarginparMoved(page) => write!(
+                f,
+                "Margin note on page {} was moved down to avoid overlapping.",
+                Paint::white(page).bold()
+            ),
+            LogItemType::NotInOuterParMode(input) => write!(
+                f,
+                "Float or margin note near {} cannot be used inside a box, float or another margin note.",
+                Paint::white(input).bold()
+            ),
+            LogItemType::OptionClash(package, loaded, requested) => if loaded.is_empty()
+                && requested.is_empty()
+            {
+                write!(
+                    f,
+                    "Package {} is loaded again with different options, which are ignored.",
+                    Paint::cyan(package)
+                )
+            } else {
+                write!(
+                    f,
+                    "Package {} is already loaded with options [{}], options [{}] given here are ignored (pass all options to the first load).",
+                    Paint::cyan(package),
+                    Paint::white(loaded).bold(),
+                    Paint::white(requested).bold()
+                )
+            },
+            LogItemType::WrongLoadingOrder(first, second) => write!(
+                f,
+                "Package {} must be loaded after package {}.",
+                Paint::cyan(first),
+                Paint::cyan(second)
+            ),
+            LogItemType::TwoDocumentClasses => write!(
+                f,
+                "Document class is declared more than once, only the first one is used."
+            ),
+            LogItemType::PdfStringTokenRemoved(token) => write!(
+                f,
+                "Token {} cannot be used in PDF bookmarks and was removed (use {}).",
+                Paint::cyan(token),
+                Paint::cyan("\\texorpdfstring")
+            ),
+            LogItemType::BookmarkLevelDifference(difference) => write!(
+                f,
+                "Bookmark level jumps by {}, some sectioning level is skipped.",
+                Paint::white(difference).bold()
+            ),
+            LogItemType::DuplicateDestination(destination) => write!(
+                f,
+                "Link destination {} is defined more than once, links may lead to a wrong place.",
+                Paint::cyan(destination)
+            ),
+            LogItemType::EngineError(engine, ref kind, file, message)
+            | LogItemType::EngineWarning(engine, ref kind, file, message) => {
+                write!(f, "{} reported a problem with {}", Paint::white(engine).bold(), kind)?;
+
+                if !file.is_empty() {
+                    write!(f, " in file {}", Paint::cyan(file))?;
+                }
+
+                write!(f, ": {}", message.replace("\n", "").trim())
+            }
+            LogItemType::LuaError(chunk, line, message, ref traceback) => {
+                write!(
+                    f,
+                    "Lua error in {} on line {}: {}",
+                    Paint::cyan(chunk),
+                    Paint::white(line).bold(),
+                    message
+                )?;
+
+                if !traceback.is_empty() {
+                    write!(f, " (traceback: {})", traceback.join(" <- "))?;
+                }
+
+                Ok(())
+            }
+            LogItemType::Expl3Error(module, message, help) => {
+                write!(
+                    f,
+                    "{} reports: {}",
+                    Paint::cyan(module),
+                    join_message_lines(message, "(")
+                )?;
+
+                if !help.is_empty() {
+                    write!(f, " {}", Paint::white(join_message_lines(help, "|")).italic())?;
+                }
+
+                Ok(())
+            }
+            LogItemType::UnknownKey(family, key, suggestion) => {
+                write!(
+                    f,
+                    "Unknown key {} in family {}",
+                    Paint::cyan(key),
+                    Paint::white(family).bold()
+                )?;
+
+                if suggestion.is_empty() {
+                    write!(f, " (is it misspelled or is a library missing?).")
+                } else {
+                    write!(f, ", did you mean {}?", Paint::cyan(suggestion))
+                }
+            }
+            LogItemType::TikzPathGaveUp(input) => write!(
+                f,
+                "TikZ path before {} cannot be parsed, is a semicolon missing at its end?",
+                Paint::white(input).bold()
+            ),
+            LogItemType::PgfplotsError(message) => write!(
+                f,
+                "{} reports: {}",
+                Paint::cyan("pgfplots"),
+                message.replace("\n", "").trim()
+            ),
+            LogItemType::PgfplotsCompatibilityMode(version) => write!(
+                f,
+                "Package pgfplots runs in backwards compatibility mode, add {} to the preamble.",
+                Paint::cyan(format!(
+                    "\\pgfplotsset{{compat={}}}",
+                    if version.is_empty() { "newest" } else { version }
+                ))
+            ),
+            LogItemType::RerunBibliographyTool(tool, file) => write!(
+                f,
+                "Run {} on {} and then LaTeX again to get the bibliography.",
+                Paint::cyan(tool),
+                Paint::white(file).bold()
+            ),
+            LogItemType::BibliographyDataEncoding(encoding, advice) => write!(
+                f,
+                "Bibliography data encoding is {}. {}",
+                Paint::cyan(encoding),
+                join_message_lines(advice, "(")
+            ),
+            LogItemType::UndefinedCitation(key, page) => write!(
+                f,
+                "Citation {} on page {} is undefined.",
+                Paint::cyan(key),
+                Paint::white(page).bold()
+            ),
+            LogItemType::MultiplyDefinedCitation(key) => if key.is_empty() {
+                write!(f, "Some citation keys are defined more than once.")
+            } else {
+                write!(
+                    f,
+                    "Citation key {} is defined more than once.",
+                    Paint::cyan(key)
+                )
+            },
+            LogItemType::IncompatiblePackage(package, other) => write!(
+                f,
+                "Package {} cannot be used together with {}.",
+                Paint::cyan(other),
+                Paint::cyan(package)
+            ),
+            LogItemType::CapacityExceeded(capacity, limit, "") => write!(
+                f,
+                "TeX capacity exceeded ({}={}), {}.",
+                Paint::cyan(capacity),
+                limit,
+                match texmf_variable(capacity) {
+                    Some(variable) => format!(
+                        "increase {} in texmf.cnf if the document is really that large",
+                        Paint::white(variable).bold()
+                    ),
+                    None => String::from("look for a macro which calls itself infinitely"),
+                }
+            ),
+            LogItemType::CapacityExceeded(capacity, limit, command) => write!(
+                f,
+                "TeX capacity exceeded ({}={}), command {} probably calls itself infinitely (fix its definition, increasing the limit won't help).",
+                Paint::cyan(capacity),
+                limit,
+                Paint::white(command).bold()
+            ),
+            LogItemType::VerbEndedByEndOfLine => write!(
+                f,
+                "Command {} is not ended on the same line, check its closing delimiter.",
+                Paint::cyan("\\verb")
+            ),
+            LogItemType::VerbInArgument => write!(
+                f,
+                "Command {} cannot be used in an argument of another command, use {} instead.",
+                Paint::cyan("\\verb"),
+                Paint::cyan("\\texttt")
+            ),
+            LogItemType::FragileCommand("", moving) => write!(
+                f,
+                "A fragile command is used in {}, put {} before it.",
+                moving_argument_name(moving),
+                Paint::cyan("\\protect")
+            ),
+            LogItemType::FragileCommand(command, moving) => write!(
+                f,
+                "Fragile command {} is used in {}, put {} before it.",
+                Paint::cyan(command),
+                moving_argument_name(moving),
+                Paint::cyan("\\protect")
+            ),
+            LogItemType::MisplacedAlignment("&", input) => write!(
+                f,
+                "Character & is used outside of a table near {}, write {} to typeset it.",
+                Paint::white(input).bold(),
+                Paint::cyan("\\&")
+            ),
+            LogItemType::MisplacedAlignment("\\noalign", input) => write!(
+                f,
+                "Command {} (or {}) is not at the beginning of a table row near {}, end the previous row with {}.",
+                Paint::cyan("\\hline"),
+                Paint::cyan("\\noalign"),
+                Paint::white(input).bold(),
+                Paint::cyan("\\\\")
+            ),
+            LogItemType::MisplacedAlignment(command, input) => write!(
+                f,
+                "Command {} (or {}) is not at the beginning of a table cell near {}.",
+                Paint::cyan("\\multicolumn"),
+                Paint::cyan(command),
+                Paint::white(input).bold()
+            ),
+            LogItemType::IllegalPreambleToken("", spec) => write!(
+                f,
+                "Column specification {} of a table contains an unknown character.",
+                Paint::white(spec).bold()
+            ),
+            LogItemType::IllegalPreambleToken(token, spec) => write!(
+                f,
+                "Column specification {} of a table contains unknown token {}, column {} is used instead.",
+                Paint::white(spec).bold(),
+                Paint::cyan(token),
+                Paint::cyan("c")
+            ),
+            LogItemType::MissingTitleData(data) => write!(
+                f,
+                "Document {} is not given, set it by {} before {}.",
+                data,
+                Paint::cyan(format!("\\{}", data)),
+                Paint::cyan("\\maketitle")
+            ),
+            LogItemType::RerunForPdfData(data) => write!(
+                f,
+                "Run LaTeX again to get {} in the PDF.",
+                Paint::white(data).bold()
+            ),
+            LogItemType::UnusedGlobalOptions(options) => write!(
+                f,
+                "Document class options [{}] are not used by the class nor by any package, check their spelling.",
+                Paint::cyan(options)
+            ),
+            LogItemType::FileWritten(file) => {
+                write!(f, "File {} was written.", Paint::white(file).bold())
+            }
+            LogItemType::ProvidedNameMismatch(kind, requested, provided) => write!(
+                f,
+                "Requested {} {} provides itself as {}, check the name of the file.",
+                kind,
+                Paint::cyan(requested),
+                Paint::cyan(provided)
+            ),
+            LogItemType::EmptyBibliography => write!(
+                f,
+                "Bibliography is empty, is there anything cited?"
+            ),
+            LogItemType::LayoutLengthTooSmall(length, current, required) => write!(
+                f,
+                "Length {} is too small ({}), add {} to the preamble.",
+                Paint::cyan(length),
+                Paint::white(current).bold(),
+                Paint::white(format!("\\setlength{{{}}}{{{}}}", length, required)).bold()
+            ),
+            LogItemType::GeometryOverSpecification(direction, dimension, value) => write!(
+                f,
+                "Page geometry is over-specified in {} direction, {} ({}) is ignored (remove it from geometry options).",
+                Paint::white(if direction == "h" { "horizontal" } else { "vertical" }).bold(),
+                Paint::cyan(dimension),
+                value
+            ),
+            LogItemType::MicrotypeUnknownSlot(character, encoding) => write!(
+                f,
+                "Character {} is not in font encoding {}, microtype settings for it are ignored.",
+                Paint::cyan(character),
+                Paint::white(encoding).bold()
+            ),
+            LogItemType::BadTypeArea(message) => write!(
+                f,
+                "Bad type area settings. {} Try {} class option.",
+                join_message_lines(message, "("),
+                Paint::white("DIV=calc").bold()
+            ),
+            LogItemType::MissingHyphenationPatterns(language) => write!(
+                f,
+                "Hyphenation patterns for language {} are not available (install TeX Live package {} and rebuild the formats).",
+                Paint::cyan(language),
+                Paint::white(language_packages(language).1).bold()
+            ),
+            LogItemType::UnknownLanguage("babel", language) => write!(
+                f,
+                "Language {} is unknown to babel, the language definition file {} was not found (check the spelling or install TeX Live package {}).",
+                Paint::cyan(language),
+                Paint::white(format!("{}.ldf", language)).bold(),
+                Paint::white(language_packages(language).0).bold()
+            ),
+            LogItemType::UnknownLanguage(package, language) => write!(
+                f,
+                "Language {} is unknown to {} (check the spelling, language definition files are part of the package).",
+                Paint::cyan(language),
+                Paint::cyan(package)
+            ),
+        }
+    }
+}
+
+/// Decodes the code point of a character as reported in log file. Besides the character itself,
+/// TeX uses `^^xx` notation for characters it cannot print.
+fn decode_character(character: &str) -> Option<u32> {
+    if character.starts_with("^^") && character.len() == 4 {
+        u32::from_str_radix(&character[2..], 16).ok()
+    } else if character.starts_with("^^") && character.len() == 3 {
+        // ^^ followed by a single character denotes the character with code shifted by 64
+        character.chars().nth(2).map(|c| (c as u32) ^ 0x40)
+    } else {
+        character.chars().next().map(|c| c as u32)
+    }
+}
+
+/// Formats the code point of a character as reported in log file.
+fn code_point(character: &str) -> String {
+    match decode_character(character) {
+        Some(code) => format!("U+{:04X}", code),
+        None => String::from("U+?"),
+    }
+}
+
+/// Returns LaTeX replacement for common Unicode characters which usually get into the source by
+/// copying text from word processors.
+fn unicode_replacement(code: u32) -> Option<&'static str> {
+    match code {
+        0x00A0 => Some("~"),
+        0x00AB => Some("\\guillemotleft"),
+        0x00AD => Some("\\-"),
+        0x00B0 => Some("\\textdegree"),
+        0x00BB => Some("\\guillemotright"),
+        0x00D7 => Some("$\\times$"),
+        0x2002 => Some("\\enspace"),
+        0x2003 => Some("\\quad"),
+        0x2009 | 0x202F => Some("\\,"),
+        0x200B | 0xFEFF => Some("nothing (remove it)"),
+        0x2010 | 0x2011 => Some("-"),
+        0x2013 => Some("--"),
+        0x2014 => Some("---"),
+        0x2018 => Some("`"),
+        0x2019 => Some("'"),
+        0x201A => Some("\\quotesinglbase"),
+        0x201C => Some("``"),
+        0x201D => Some("''"),
+        0x201E => Some("\\quotedblbase"),
+        0x2022 => Some("\\textbullet"),
+        0x2026 => Some("\\ldots"),
+        0x2032 => Some("$'$"),
+        0x2039 => Some("\\guilsinglleft"),
+        0x203A => Some("\\guilsinglright"),
+        0x20AC => Some("\\euro"),
+        0x2212 => Some("$-$"),
+        0xFB00 => Some("ff"),
+        0xFB01 => Some("fi"),
+        0xFB02 => Some("fl"),
+        0xFB03 => Some("ffi"),
+        0xFB04 => Some("ffl"),
+        _ => None,
+    }
+}
+
+/// Finds the location in a LaTeX or package message, which is usually at the end of the message
+/// in the form of `on input line 42.`
+fn input_line(message: &str) -> Location {
+    let pattern = Regex::new(r"on input line (\d+)\.").unwrap();
+
+    match pattern.captures(message) {
+        Some(captures) => Location::Line(usize::from_str(&captures[1]).unwrap()),
+        None => Location::None,
+    }
+}
+
+/// Splits a pdfTeX message to the hint (in parentheses after the engine name), the file the
+/// message is related to and the message itself.
+fn parse_pdftex_message(text: &str) -> (&str, &str, &str) {
+    let pattern = Regex::new(
+        r"^(?:! )?pdfTeX (?:error|warning)(?: \(([^)]+)\))?: (?:\S+ \(file ([^)]+)\): )?((?:.|\n)*)$",
+    ).unwrap();
+
+    match pattern.captures(text) {
+        Some(captures) => (
+            captures.get(1).map_or("", |hint| hint.as_str()),
+            captures.get(2).map_or("", |file| file.as_str()),
+            captures.get(3).unwrap().as_str(),
+        ),
+        None => ("", "", text),
+    }
+}
+
+/// Finds the name of a file mentioned in an engine message.
+fn message_file(message: &str) -> &str {
+    let pattern = Regex::new(r#"(?:\(file |file [`"'])([^)`"'\s]+)"#).unwrap();
+
+    match pattern.captures(message) {
+        Some(captures) => captures.get(1).unwrap().as_str(),
+        None => "",
+    }
+}
+
+/// Joins lines of a multi-line message into one line. Each line can be prefixed with a padding
+/// starting with `prefix`, for example `(siunitx)` or `|`, which is removed.
+fn join_message_lines(message: &str, prefix: &str) -> String {
+    message
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+
+            match line.strip_prefix(prefix) {
+                Some(line) if prefix == "(" => {
+                    line.split_once(')').map_or("", |(_, line)| line).trim()
+                }
+                Some(line) => line.trim(),
+                None => line,
+            }
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Returns names of TeX Live packages which provide the babel language definition and the
+/// hyphenation patterns for a language.
+fn language_packages(language: &str) -> (String, String) {
+    let language = language.to_lowercase();
+
+    // babel supports several names for some languages
+    let (babel, patterns) = match language.as_str() {
+        "american" | "british" | "english" | "ukenglish" | "usenglish" | "australian"
+        | "canadian" | "newzealand" => ("english", "english"),
+        "german" | "ngerman" | "austrian" | "naustrian" | "swissgerman" | "nswissgerman" => {
+            ("german", "german")
+        }
+        "french" | "francais" | "acadian" | "canadien" => ("french", "french"),
+        "portuges" | "portuguese" | "brazil" | "brazilian" => ("portuges", "portuguese"),
+        "slovak" => ("slovak", "slovak"),
+        "czech" => ("czech", "czech"),
+        "magyar" | "hungarian" => ("hungarian", "hungarian"),
+        "russian" => ("russian", "russian"),
+        "ukrainian" => ("ukrainian", "ukrainian"),
+        "spanish" | "mexican" => ("spanish", "spanish"),
+        "greek" | "polutonikogreek" => ("greek", "greek"),
+        "norsk" | "nynorsk" | "norwegian" => ("norsk", "norwegian"),
+        other => (other, other),
+    };
+
+    (format!("babel-{}", babel), format!("hyphen-{}", patterns))
+}
+
+/// Recognizes a table environment from internal commands in the context lines of an error or
+/// from the source line. Tabularx reads its whole body before typesetting it, so the source line is
+/// the end of the environment.
+fn table_environment(error: &str) -> &'static str {
+    if error.contains("\\LT@") || error.contains("{longtable}") {
+        "longtable"
+    } else if error.contains("\\TX@") || error.contains("{tabularx}") {
+        "tabularx"
+    } else {
+        ""
+    }
+}
+
+/// Finds the column specification of a table, which is the last group in curly braces on the
+/// line with the beginning of the table.
+fn column_specification(input: &str) -> &str {
+    let pattern = Regex::new(r"\{([^{}]*)\}[^{}]*$").unwrap();
+
+    match pattern.captures(input) {
+        Some(captures) => captures.get(1).unwrap().as_str(),
+        None => "",
+    }
+}
+
+/// Internal commands which process moving arguments, which are written to auxiliary files.
+const MOVING_ARGUMENT_COMMANDS: &[&str] = &["\\@caption", "\\@sect", "\\@ssect", "\\protect"];
+
+/// Finds an internal command processing a moving argument in the context lines of an error.
+fn moving_argument_command(context: &str) -> Option<&'static str> {
+    MOVING_ARGUMENT_COMMANDS
+        .iter()
+        .find(|command| {
+            context
+                .match_indices(*command)
+                .any(|(index, _)| match context[index + command.len()..].chars().next() {
+                    Some(next) => !next.is_alphabetic() && next != '@',
+                    None => true,
+                })
+        })
+        .cloned()
+}
+
+/// Describes a moving argument processed by an internal command.
+fn moving_argument_name(command: &str) -> &str {
+    match command {
+        "\\@caption" => "a caption",
+        "\\@sect" | "\\@ssect" => "a section title",
+        _ => "a moving argument",
+    }
+}
+
+/// Capacities which are exceeded practically only because of infinite recursion.
+const RECURSION_CAPACITIES: &[&str] = &[
+    "input stack size",
+    "grouping levels",
+    "semantic nest size",
+    "parameter stack size",
+    "save size",
+    "text input levels",
+];
+
+/// Returns the texmf.cnf variable which sets a TeX capacity, if it can be configured.
+fn texmf_variable(capacity: &str) -> Option<&'static str> {
+    match capacity {
+        "main memory size" => Some("main_memory"),
+        "pool size" => Some("pool_size"),
+        "buffer size" => Some("buf_size"),
+        "hash size" => Some("hash_extra"),
+        "font memory" => Some("font_mem_size"),
+        "exception dictionary" => Some("hyph_size"),
+        "input stack size" => Some("stack_size"),
+        "save size" => Some("save_size"),
+        "parameter stack size" => Some("param_size"),
+        "semantic nest size" => Some("nest_size"),
+        "number of strings" => Some("max_strings"),
+        "text input levels" => Some("max_in_open"),
+        _ => None,
+    }
+}
+
+/// Finds the macro which appears most often in the context lines of an error and how many times
+/// it appears. When the error context is limited (LaTeX shows only the innermost macro by
+/// default), the macro appears only once.
+fn most_expanded_macro(context: &str) -> Option<(&str, usize)> {
+    let pattern = Regex::new(r"(?m)^(\\(?:[A-Za-z@]+|.))[^\n]*->").unwrap();
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+
+    for captures in pattern.captures_iter(context) {
+        let name = captures.get(1).unwrap().as_str();
+
+        match counts.iter().position(|&(macro_name, _)| macro_name == name) {
+            Some(index) => counts[index].1 += 1,
+            None => counts.push((name, 1)),
+        }
+    }
+
+    // the first of the most frequent macros is the innermost one
+    counts
+        .iter()
+        .fold(None, |best: Option<(&str, usize)>, &(name, count)| match best {
+            Some((_, best_count)) if best_count >= count => best,
+            _ => Some((name, count)),
+        })
+}
+
+/// Commonly used keys of TikZ, used for suggestions when an unknown key is found.
+const TIKZ_KEYS: &[&str] = &[
+    "->", "<-", "<->", "above", "above left", "above right", "align", "anchor", "arrows",
+    "ball color", "below", "below left", "below right", "black", "blue", "bottom color", "brown",
+    "circle", "color", "cyan", "dashed", "densely dashed", "densely dotted", "dotted", "double",
+    "draw", "draw opacity", "ellipse", "every node/.style", "fill", "fill opacity", "font",
+    "gray", "green", "help lines", "inner sep", "label", "left", "left color", "line cap",
+    "line join", "line width", "loosely dashed", "magenta", "minimum height", "minimum size",
+    "minimum width", "node distance", "opacity", "orange", "outer sep", "pattern", "pin",
+    "purple", "rectangle", "red", "right", "right color", "rotate", "rounded corners", "scale",
+    "semithick", "shade", "shading", "shape", "shift", "solid", "text", "text depth",
+    "text height", "text width", "thick", "thin", "top color", "ultra thick", "ultra thin",
+    "very thick", "very thin", "violet", "white", "xscale", "xshift", "yellow", "yscale",
+    "yshift",
+];
+
+/// Commonly used keys of pgfplots, used for suggestions when an unknown key is found.
+const PGFPLOTS_KEYS: &[&str] = &[
+    "axis lines", "axis x line", "axis y line", "bar width", "colormap", "compat", "domain",
+    "enlargelimits", "grid", "height", "legend entries", "legend pos", "legend style",
+    "major grid style", "mark", "mark options", "minor tick num", "no markers", "only marks",
+    "samples", "smooth", "title", "width", "x tick label style", "xbar", "xlabel", "xmax", "xmin",
+    "xmode", "xtick", "xticklabels", "ybar", "ylabel", "ymax", "ymin", "ymode", "ytick",
+    "yticklabels",
+];
+
+/// Finds the known key which is the most similar to the unknown one. Returns an empty string if
+/// there is no such key.
+fn suggest_key(family: &str, key: &str) -> &'static str {
+    let keys = if family.starts_with("/pgfplots") {
+        PGFPLOTS_KEYS
+    } else {
+        TIKZ_KEYS
+    };
+
+    // allow one typo in short keys and more in longer ones
+    let limit = 1 + key.len() / 5;
+
+    keys.iter()
+        .map(|known| (edit_distance(key, known), *known))
+        .filter(|&(distance, _)| distance <= limit)
+        .min()
+        .map_or("", |(_, known)| known)
+}
+
+/// Trait for all rules. The task of a rule is specifying the regular expression which is used to
+/// extract information from log file. Then it gets found captures from which the rule creates
+/// corresponding log item. Optionally, the rule can specify custom retrieval of captures from log
+/// file.
+pub trait Rule<'a> {
+    /// Returns regular expression for extraction of information from log file.
+    fn get_regex(&self) -> Regex;
+
+    /// Transforms captures to log item.
+    fn process(&'a self, Captures<'a>) -> LogItem<'a>;
+
+    /// Transforms all captures found in a file to log items. Custom mechanism can be implemented,
+    /// for example to merge related captures into one log item.
+    fn process_all(&'a self, captures: Vec<Captures<'a>>) -> Vec<LogItem<'a>> {
+        captures
+            .into_iter()
+            .map(|captures| self.process(captures))
+            .collect()
+    }
+
+    /// Retrieves captures from log file. Custom mechanism can be implemented.
+    fn captures(&'a self, pattern: Regex, log: &'a str) -> Vec<Captures<'a>> {
+        pattern.captures_iter(log).collect()
+    }
+}
+
+// RULES
+
+pub struct UndefinedControlSequence;
+pub struct TooManyEndingBraces;
+pub struct NotInMathMode;
+pub struct RunawayArgument;
+pub struct RunawayArgument2;
+pub struct UnderfullHBox;
+pub struct OverfullHBox;
+pub struct MissingFile;
+pub struct CannotFindFile;
+pub struct NoBibliographyFile;
+pub struct UnknownGraphicsExtension;
+pub struct InvalidOption;
+pub struct ExtraAlignmentToCR;
+pub struct MissingEndingBrace;
+pub struct MissingStartingBrace;
+pub struct ExtraGroupEnd;
+pub struct UnmatchedCommand;
+pub struct IncompleteConditional;
+pub struct EndInsideGroup;
+pub struct UnclosedGroup;
+pub struct UnclosedConditional;
+pub struct MissingNumber;
+pub struct IllegalUnit;
+pub struct DimensionTooLarge;
+pub struct ArithmeticOverflow;
+pub struct NumberTooBig;
+pub struct CommandAlreadyDefined;
+pub struct IllegalParameterNumber;
+pub struct MisplacedParameterCharacter;
+pub struct DefinitionMismatch;
+pub struct ArgumentExtraBrace;
+pub struct PreambleOnly;
+pub struct FontShapeUndefined;
+pub struct FontShapesSubstituted;
+pub struct MissingCharacter;
+pub struct FontNotLoadable;
+pub struct UnicodeCharacterNotSetUp;
+pub struct InvalidUtf8;
+pub struct InvalidCharacter;
+pub struct UndefinedKeyboardCharacter;
+pub struct FloatSpecifierChanged;
+pub struct FloatTooLarge;
+pub struct TooManyFloats;
+pub struct MarginparMoved;
+pub struct NotInOuterParMode;
+pub struct OptionClash;
+pub struct WrongLoadingOrder;
+pub struct TwoDocumentClasses;
+pub struct PdfStringTokenRemoved;
+pub struct BookmarkLevelDifference;
+pub struct DuplicateDestination;
+pub struct PdfTeXError;
+pub struct PdfTeXWarning;
+pub struct XdvipdfmxMessage;
+pub struct LuaTeXBackendMessage;
+pub struct LuaError;
+pub struct Expl3Error;
+pub struct UnknownKey;
+pub struct TikzPathGaveUp;
+pub struct PgfplotsError;
+pub struct PgfplotsCompatibilityMode;
+pub struct RerunBibliographyTool;
+pub struct BibliographyDataEncoding;
+pub struct UndefinedCitation;
+pub struct MultiplyDefinedCitation;
+pub struct IncompatiblePackage;
+pub struct EmptyBibliography;
+pub struct LayoutLengthTooSmall;
+pub struct GeometryOverSpecification;
+pub struct MicrotypeUnknownSlot;
+pub struct BadTypeArea;
+pub struct MissingHyphenationPatterns;
+pub struct UnknownLanguage;
+pub struct CapacityExceeded;
+pub struct VerbEndedByEndOfLine;
+pub struct VerbInArgument;
+pub struct MisplacedAlignment;
+pub struct IllegalPreambleToken;
+pub struct IllegalArrayCharacter;
+pub struct MissingTitleData;
+pub struct RerunForPdfData;
+pub struct UnusedGlobalOptions;
+pub struct FileWritten;
+pub struct ProvidedNameMismatch;
+
+impl<'a> Rule<'a> for UndefinedControlSequence {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Undefined control sequence\.")
+            .raw(r"(")
+            .context()
+            .raw(r")")
+            .location()
+            .line(r".*(\\[^{\s]+).*")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let context = captures.get(1).unwrap().as_str();
+
+        // the undefined command is the last one on the first context line
+        let item_type = match moving_argument_command(context) {
+            Some(moving) => LogItemType::FragileCommand(
+                Regex::new(r"(\\(?:[A-Za-z@]+|.))\s*$")
+                    .unwrap()
+                    .captures(context.lines().next().unwrap_or(""))
+                    .map_or("", |captures| captures.get(1).unwrap().as_str()),
+                moving,
+            ),
+            None => LogItemType::UndefinedControlSequence(captures.get(3).unwrap().as_str()),
+        };
+
+        LogItem::new(
+            item_type,
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for TooManyEndingBraces {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Too many }'s\.")
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::TooManyEndingBraces(captures.get(2).unwrap().as_str()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for NotInMathMode {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Missing \$ inserted\.")
+            .line("<inserted text> ")
+            .line(r".+\$")
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::NotInMathMode(captures.get(2).unwrap().as_str()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+
+    fn captures(&'a self, pattern: Regex, log: &'a str) -> Vec<Captures<'a>> {
+        pattern.captures_iter(log).step_by(2).collect()
+    }
+}
+
+impl<'a> Rule<'a> for RunawayArgument {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"Runaway argument\?")
+            .any_on_line()
+            .error(r"Paragraph ended before (\S+) was complete\.")
+            .any_on_line()
+            .any_on_line()
+            .location()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::RunawayArgument(captures.get(1).unwrap().as_str()),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap() - 1),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for RunawayArgument2 {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"Runaway argument\?")
+            .any_on_line()
+            .error(r"File ended while scanning use of (\S+)\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::RunawayArgument(captures.get(1).unwrap().as_str()),
+            Location::End,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for UnderfullHBox {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"Underfull \\hbox \(badness (\d+)\) in paragraph at lines (\d+)--\d+")
+            .line(r"(.*)")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::UnderfullHBox(
+                captures.get(3).unwrap().as_str(),
+                usize::from_str(captures.get(1).unwrap().as_str()).unwrap(),
+            ),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for OverfullHBox {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"Overfull \\hbox \([^)]+\) in paragraph at lines (\d+)--\d+")
+            .raw(r"\S+ ((?:.|\s)+)\[\]")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::OverfullHBox(captures.get(2).unwrap().as_str()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for MissingFile {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"(?:LaTeX|Package \S+) Error: File `([^']+)' not found(?:: using draft setting)?\.")
+            .raw(r"(?:")
+            .context()
+            .location_with_arg()
+            .raw(r")?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let filename = captures.get(1).unwrap().as_str();
+
+        match (captures.get(2), captures.get(3)) {
+            (Some(line), Some(input)) => LogItem::new(
+                LogItemType::MissingFile(filename, FileKind::classify(filename, input.as_str())),
+                Location::Line(usize::from_str(line.as_str()).unwrap()),
+            ),
+            _ => LogItem::new(
+                LogItemType::MissingFile(filename, FileKind::classify(filename, "")),
+                Location::None,
+            ),
+        }
+    }
+}
+
+impl<'a> Rule<'a> for CannotFindFile {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"I can't find file `([^']+)'\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let filename = captures.get(1).unwrap().as_str();
+
+        // TeX itself looks only for input files
+        let kind = match FileKind::classify(filename, captures.get(3).unwrap().as_str()) {
+            FileKind::Other => FileKind::Input,
+            kind => kind,
+        };
+
+        LogItem::new(
+            LogItemType::MissingFile(filename, kind),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for NoBibliographyFile {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new().line(r"No file (\S+\.bbl)\.").into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::MissingFile(captures.get(1).unwrap().as_str(), FileKind::Bibliography),
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for UnknownGraphicsExtension {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"LaTeX Error: Unknown graphics extension: (\S+)\.")
+            .context()
+            .location()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::UnknownGraphicsExtension(captures.get(1).unwrap().as_str()),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for InvalidOption {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"LaTeX Error: Unknown option `([^']+)' for package `([^']+)'\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::InvalidOption(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+            ),
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for ExtraAlignmentToCR {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Extra alignment tab has been changed to \\cr\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::ExtraAlignmentToCR(
+                table_environment(captures.get(0).unwrap().as_str()),
+                captures.get(2).unwrap().as_str().trim(),
+            ),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for MissingEndingBrace {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Missing } inserted\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::MissingEndingBrace(captures.get(2).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for MissingStartingBrace {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Missing \{ inserted\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::MissingStartingBrace(captures.get(2).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for ExtraGroupEnd {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Extra \}, or forgotten \\endgroup\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::ExtraGroupEnd(captures.get(2).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for UnmatchedCommand {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Extra (\\[a-zA-Z]+)\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::UnmatchedCommand(
+                captures.get(1).unwrap().as_str(),
+                captures.get(3).unwrap().as_str().trim(),
+            ),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for IncompleteConditional {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Incomplete (\\[a-zA-Z]+); all text was ignored after line (\d+)\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::IncompleteConditional(captures.get(1).unwrap().as_str()),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for EndInsideGroup {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"\(\\end occurred inside a group at level (\d+)\)")
+            .raw(r"\n*(###)?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::EndInsideGroup(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+            Location::End,
+        )
+    }
+
+    fn captures(&'a self, pattern: Regex, log: &'a str) -> Vec<Captures<'a>> {
+        // the open groups are listed one by one when the engine supports it
+        pattern
+            .captures_iter(log)
+            .filter(|captures| captures.get(2).is_none())
+            .collect()
+    }
+}
+
+impl<'a> Rule<'a> for UnclosedGroup {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"### (.+) \(level (\d+)\) entered at line (\d+)(?: \((.*)\))?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::UnclosedGroup(
+                captures.get(1).unwrap().as_str(),
+                usize::from_str(captures.get(2).unwrap().as_str()).unwrap(),
+                captures.get(4).map_or("", |opening| opening.as_str()),
+            ),
+            Location::Line(usize::from_str(captures.get(3).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for UnclosedConditional {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"\(\\end occurred when (\\[a-zA-Z]+) on line (\d+) was incomplete\)")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::UnclosedConditional(captures.get(1).unwrap().as_str()),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for MissingNumber {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Missing number, treated as zero\.")
+            .context()
+            .location_with_arg()
+            .raw(r"(?:\n")
+            .context()
+            .error(r"Illegal unit of measure \(pt inserted\)\.")
+            .context()
+            .location()
+            .raw(r")?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let line = captures.get(1).unwrap().as_str();
+        let input = captures.get(2).unwrap().as_str().trim();
+
+        // missing number immediately followed by illegal unit on the same line is in fact
+        // a missing length
+        let item_type = match captures.get(3) {
+            Some(unit_line) if unit_line.as_str() == line => LogItemType::MissingDimension(input),
+            _ => LogItemType::MissingNumber(input),
+        };
+
+        LogItem::new(item_type, Location::Line(usize::from_str(line).unwrap()))
+    }
+}
+
+impl<'a> Rule<'a> for IllegalUnit {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"(?:! Missing number, treated as zero\.\n")
+            .context()
+            .location()
+            .raw(r".*\n")
+            .context()
+            .raw(r")?")
+            .error(r"Illegal unit of measure \(pt inserted\)\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::IllegalUnit(captures.get(3).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+
+    fn captures(&'a self, pattern: Regex, log: &'a str) -> Vec<Captures<'a>> {
+        // the pair of missing number and illegal unit on the same line is handled by
+        // MissingNumber rule
+        pattern
+            .captures_iter(log)
+            .filter(|captures| match captures.get(1) {
+                Some(line) => line.as_str() != captures.get(2).unwrap().as_str(),
+                None => true,
+            })
+            .collect()
+    }
+}
+
+impl<'a> Rule<'a> for DimensionTooLarge {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Dimension too large\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::DimensionTooLarge(captures.get(2).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for ArithmeticOverflow {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Arithmetic overflow\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::ArithmeticOverflow(captures.get(2).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for NumberTooBig {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Number too big\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::NumberTooBig(captures.get(2).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for CommandAlreadyDefined {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"LaTeX Error: Command (\S+) already defined\.")
+            .context()
+            .location()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::CommandAlreadyDefined(captures.get(1).unwrap().as_str()),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for IllegalParameterNumber {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Illegal parameter number in definition of (\S+)\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::IllegalParameterNumber(
+                captures.get(1).unwrap().as_str(),
+                captures.get(3).unwrap().as_str().trim(),
+            ),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for MisplacedParameterCharacter {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"You can't use `macro parameter character #' in (\S+) mode\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::MisplacedParameterCharacter(
+                captures.get(1).unwrap().as_str(),
+                captures.get(3).unwrap().as_str().trim(),
+            ),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for DefinitionMismatch {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Use of (\S+) doesn't match its definition\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::DefinitionMismatch(
+                captures.get(1).unwrap().as_str(),
+                captures.get(3).unwrap().as_str().trim(),
+            ),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for ArgumentExtraBrace {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Argument of (\S+) has an extra \}\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let command = captures.get(1).unwrap().as_str();
+
+        // a fragile command in a moving argument breaks the internal command processing it
+        let item_type = match moving_argument_command(command) {
+            Some(moving) => LogItemType::FragileCommand("", moving),
+            None => {
+                LogItemType::ArgumentExtraBrace(command, captures.get(3).unwrap().as_str().trim())
+            }
+        };
+
+        LogItem::new(
+            item_type,
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for PreambleOnly {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"LaTeX Error: Can be used only in preamble\.")
+            .context()
+            .location()
+            .line(r".*(\\[a-zA-Z@]+)\s*")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::PreambleOnly(captures.get(2).unwrap().as_str()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for FontShapeUndefined {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"LaTeX Font Warning: Font shape `([^']+)' undefined")
+            .raw(r"\(Font\)\s+using `([^']+)' instead(?: on input line (\d+))?\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::FontShapeUndefined(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+            ),
+            match captures.get(3) {
+                Some(line) => Location::Line(usize::from_str(line.as_str()).unwrap()),
+                None => Location::None,
+            },
+        )
+    }
+}
+
+impl<'a> Rule<'a> for FontShapesSubstituted {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"LaTeX Font Warning: Some font shapes were not available, defaults substituted\.")
+            .into()
+    }
+
+    fn process(&'a self, _captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(LogItemType::FontShapesSubstituted, Location::End)
+    }
+}
+
+impl<'a> Rule<'a> for MissingCharacter {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"Missing character: There is no (.+?)(?: \(U\+[0-9A-F]+\))? in font ([^!\n]+)!")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::MissingCharacters(
+                captures.get(2).unwrap().as_str(),
+                vec![captures.get(1).unwrap().as_str()],
+            ),
+            Location::None,
+        )
+    }
+
+    fn process_all(&'a self, captures: Vec<Captures<'a>>) -> Vec<LogItem<'a>> {
+        let mut fonts: Vec<(&'a str, Vec<&'a str>)> = Vec::new();
+
+        // group missing characters by font
+        for found in captures {
+            let character = found.get(1).unwrap().as_str();
+            let font = found.get(2).unwrap().as_str();
+
+            match fonts.iter().position(|&(other, _)| other == font) {
+                Some(index) => if !fonts[index].1.contains(&character) {
+                    fonts[index].1.push(character);
+                },
+                None => fonts.push((font, vec![character])),
+            }
+        }
+
+        fonts
+            .into_iter()
+            .map(|(font, characters)| {
+                LogItem::new(LogItemType::MissingCharacters(font, characters), Location::None)
+            })
+            .collect()
+    }
+}
+
+impl<'a> Rule<'a> for FontNotLoadable {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Font \S+?=(\S+)(?: at \S+)? not loadable: ([^.]+)\.")
+            .raw(r"(?:")
+            .context()
+            .location()
+            .raw(r")?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::FontNotLoadable(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+            ),
+            match captures.get(3) {
+                Some(line) => Location::Line(usize::from_str(line.as_str()).unwrap()),
+                None => Location::None,
+            },
+        )
+    }
+}
+
+impl<'a> Rule<'a> for UnicodeCharacterNotSetUp {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"! (?:LaTeX|Package inputenc) Error: Unicode char(?:acter)? (?:\\u8:)?(.+?)")
+            .raw(r"(?: \(U\+([0-9A-F]+)\))?\s*(?:\(inputenc\)\s*)?")
+            .line(r"not set up for use with LaTeX\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::UnicodeCharacterNotSetUp(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).map_or("", |code| code.as_str()),
+                captures.get(4).unwrap().as_str().trim(),
+            ),
+            Location::Line(usize::from_str(captures.get(3).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for InvalidUtf8 {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r#"Package inputenc Error: Invalid UTF-8 byte(?: sequence| "([0-9A-F]{2}))\."#)
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::InvalidUtf8(
+                captures.get(1).map_or("", |byte| byte.as_str()),
+                captures.get(3).unwrap().as_str().trim(),
+            ),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for InvalidCharacter {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Text line contains an invalid character\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::InvalidCharacter(captures.get(2).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for UndefinedKeyboardCharacter {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"! Package inputenc Error: Keyboard character used is undefined")
+            .line(r"\(inputenc\)\s+in inputencoding `([^']+)'\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::UndefinedKeyboardCharacter(
+                captures.get(1).unwrap().as_str(),
+                captures.get(3).unwrap().as_str().trim(),
+            ),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for FloatSpecifierChanged {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"LaTeX Warning: `([^']+)' float specifier changed to `([^']+)'\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::FloatSpecifierChanged(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+            ),
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for FloatTooLarge {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"LaTeX Warning: Float too large for page by (\S+) on input line (\d+)\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::FloatTooLarge(captures.get(1).unwrap().as_str()),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for TooManyFloats {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"LaTeX Error: Too many unprocessed floats\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::TooManyFloats(captures.get(2).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for MarginparMoved {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"LaTeX Warning: Marginpar on page (\d+) moved\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::MarginparMoved(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for NotInOuterParMode {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"LaTeX Error: Not in outer par mode\.")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::NotInOuterParMode(captures.get(2).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for OptionClash {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"LaTeX Error: Option clash for package (\S+)\.")
+            .context()
+            .location()
+            .raw(r"(?:.*\n")
+            .context()
+            .line(r"The package \S+ has already been loaded with options:")
+            .line(r"\s*\[([^\]]*)\]")
+            .line(r"There has now been an attempt to load it with options")
+            .raw(r"\s*\[([^\]]*)\])?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::OptionClash(
+                captures.get(1).unwrap().as_str(),
+                captures.get(3).map_or("", |options| options.as_str()),
+                captures.get(4).map_or("", |options| options.as_str()),
+            ),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for WrongLoadingOrder {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"(Package \S+ Warning: You have loaded (\S+?) before ([^\s.,!]+).*")
+            .continuation()
+            .raw(r")")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        // the package loaded too early is the one which must go after the other
+        LogItem::new(
+            LogItemType::WrongLoadingOrder(
+                captures.get(2).unwrap().as_str(),
+                captures.get(3).unwrap().as_str(),
+            ),
+            input_line(captures.get(1).unwrap().as_str()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for TwoDocumentClasses {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"LaTeX Error: Two \\documentclass or \\documentstyle commands\.")
+            .context()
+            .location()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::TwoDocumentClasses,
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for PdfStringTokenRemoved {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"Package hyperref Warning: Token not allowed in a PDF string \([^)]+\):")
+            .raw(r"\s+(?:\(hyperref\)\s+)?removing `(.+?)'(?: on input line (\d+))?\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::PdfStringTokenRemoved(captures.get(1).unwrap().as_str()),
+            match captures.get(2) {
+                Some(line) => Location::Line(usize::from_str(line.as_str()).unwrap()),
+                None => Location::None,
+            },
+        )
+    }
+}
+
+impl<'a> Rule<'a> for BookmarkLevelDifference {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"(Package hyperref Warning: Difference \((\d+)\) between bookmark levels.*")
+            .continuation()
+            .raw(r")")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::BookmarkLevelDifference(
+                usize::from_str(captures.get(2).unwrap().as_str()).unwrap(),
+            ),
+            input_line(captures.get(1).unwrap().as_str()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for DuplicateDestination {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"pdfTeX warning \(ext4\): destination with the same identifier \(name\{([^}]+)\}\)")
+            .line(r"\s+has\s+been\s+already\s+used, duplicate ignored")
+            .raw(r"(?:")
+            .short_context()
+            .location()
+            .raw(r")?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::DuplicateDestination(captures.get(1).unwrap().as_str()),
+            match captures.get(2) {
+                Some(line) => Location::Line(usize::from_str(line.as_str()).unwrap()),
+                None => Location::None,
+            },
+        )
+    }
+}
+
+impl<'a> Rule<'a> for PdfTeXError {
+    fn get_regex(&self) -> Regex {
+        // only the beginning, the message can be broken into more lines (see captures)
+        PatternBuilder::new().raw(r"(! pdfTeX error)").into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let (hint, file, message) = parse_pdftex_message(captures.get(1).unwrap().as_str());
+
+        LogItem::new(
+            LogItemType::EngineError(
+                "pdfTeX",
+                EngineMessageKind::classify(&format!("{} {}", hint, message)),
+                file,
+                message,
+            ),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+        )
+    }
+
+    fn captures(&'a self, pattern: Regex, log: &'a str) -> Vec<Captures<'a>> {
+        let rest = PatternBuilder::new().raw(r"\n").context().location();
+        wrapped_captures(&pattern, rest, log)
+    }
+}
+
+impl<'a> Rule<'a> for PdfTeXWarning {
+    fn get_regex(&self) -> Regex {
+        // the warning can follow a page number on the same line (see PdfTeXError for the rest)
+        PatternBuilder::new().raw(r"(pdfTeX warning)").into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let (hint, file, message) = parse_pdftex_message(captures.get(1).unwrap().as_str());
+
+        LogItem::new(
+            LogItemType::EngineWarning(
+                "pdfTeX",
+                EngineMessageKind::classify(&format!("{} {}", hint, message)),
+                file,
+                message,
+            ),
+            Location::None,
+        )
+    }
+
+    fn captures(&'a self, pattern: Regex, log: &'a str) -> Vec<Captures<'a>> {
+        // duplicate destinations are handled by DuplicateDestination rule
+        wrapped_captures(&pattern, PatternBuilder::new(), log)
+            .into_iter()
+            .filter(|captures| {
+                !captures
+                    .get(1)
+                    .unwrap()
+                    .as_str()
+                    .contains("destination with the same identifier")
+            })
+            .collect()
+    }
+}
+
+impl<'a> Rule<'a> for XdvipdfmxMessage {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"(?m)^xdvipdfmx:(warning|error|fatal): (.*)")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let message = captures.get(2).unwrap().as_str();
+        let kind = EngineMessageKind::classify(message);
+        let file = message_file(message);
+
+        LogItem::new(
+            if captures.get(1).unwrap().as_str() == "warning" {
+                LogItemType::EngineWarning("xdvipdfmx", kind, file, message)
+            } else {
+                LogItemType::EngineError("xdvipdfmx", kind, file, message)
+            },
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for LuaTeXBackendMessage {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"(?m)^(?:! )?(error|warning)\s*:?\s*\((pdf [^)]+)\)\s*:\s*(.*)")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let message = captures.get(3).unwrap().as_str();
+        let kind =
+            EngineMessageKind::classify(&format!("{} {}", captures.get(2).unwrap().as_str(), message));
+        let file = message_file(message);
+
+        LogItem::new(
+            if captures.get(1).unwrap().as_str() == "warning" {
+                LogItemType::EngineWarning("LuaTeX", kind, file, message)
+            } else {
+                LogItemType::EngineError("LuaTeX", kind, file, message)
+            },
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for LuaError {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r#"(?m)^(?:! LuaTeX error )?(?:\[string "([^"]*)"\]|\[(\\[^\]]+)\]|(\S+\.lua))"#)
+            .line(r":(\d+): (.*)")
+            .raw(r"(?:stack traceback:\n((?:\t.*\n)*))?")
+            .raw(r"(?:")
+            .short_context()
+            .location()
+            .raw(r")?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let chunk = captures
+            .get(1)
+            .or_else(|| captures.get(2))
+            .or_else(|| captures.get(3))
+            .unwrap()
+            .as_str()
+            .trim();
+
+        let traceback = match captures.get(6) {
+            Some(traceback) => traceback
+                .as_str()
+                .lines()
+                .map(|frame| frame.trim().trim_end_matches('.'))
+                .filter(|frame| !frame.is_empty())
+                .collect(),
+            None => Vec::new(),
+        };
+
+        LogItem::new(
+            LogItemType::LuaError(
+                chunk,
+                usize::from_str(captures.get(4).unwrap().as_str()).unwrap(),
+                captures.get(5).unwrap().as_str().trim(),
+                traceback,
+            ),
+            match captures.get(7) {
+                Some(line) => Location::Line(usize::from_str(line.as_str()).unwrap()),
+                None => Location::None,
+            },
+        )
+    }
+}
+
+impl<'a> Rule<'a> for Expl3Error {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"! (?:Package (\S+)|(LaTeX3?(?: \S+)?)) Error: (.*")
+            .continuation()
+            .raw(r")\n")
+            .raw(r"(?:\(\S+\)\s*|\s)*\n")
+            .line(r"For immediate help type H <return>\.")
+            .context()
+            .location()
+            .raw(r".*\n")
+            .raw(r"(?:\s*\n)*")
+            .raw(r"(?:\|'+\n((?:\|[^.\n].*\n|\|\n)*)\|\.+)?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::Expl3Error(
+                captures
+                    .get(1)
+                    .or_else(|| captures.get(2))
+                    .unwrap()
+                    .as_str(),
+                captures.get(3).unwrap().as_str(),
+                captures.get(5).map_or("", |help| help.as_str()),
+            ),
+            Location::Line(usize::from_str(captures.get(4).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for UnknownKey {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"! Package pgfkeys Error: I do not know the key '((?:[^'\n]*/)?)([^'/\n]+)'.*\n")
+            .context()
+            .location()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let family = captures.get(1).unwrap().as_str().trim_end_matches('/');
+        let key = captures.get(2).unwrap().as_str();
+
+        LogItem::new(
+            LogItemType::UnknownKey(family, key, suggest_key(family, key)),
+            Location::Line(usize::from_str(captures.get(3).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for TikzPathGaveUp {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Package tikz Error: Giving up on this path\. Did you forget a semicolon\?\.?")
+            .context()
+            .location_with_arg()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::TikzPathGaveUp(captures.get(2).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for PgfplotsError {
+    fn get_regex(&self) -> Regex {
+        // only the beginning, the message can be broken into more lines (see captures)
+        PatternBuilder::new()
+            .raw(r"! Package pgfplots Error: ()")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::PgfplotsError(captures.get(1).unwrap().as_str()),
+            match captures.get(2) {
+                Some(line) => Location::Line(usize::from_str(line.as_str()).unwrap()),
+                None => Location::None,
+            },
+        )
+    }
+
+    fn captures(&'a self, pattern: Regex, log: &'a str) -> Vec<Captures<'a>> {
+        let rest = PatternBuilder::new()
+            .raw(r"\n(?:")
+            .context()
+            .location()
+            .raw(r")?");
+        wrapped_captures(&pattern, rest, log)
+    }
+}
+
+impl<'a> Rule<'a> for PgfplotsCompatibilityMode {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"Package pgfplots Warning: running in backwards compatibility mode")
+            .raw(r"((?:.*\n){0,4}?.*on input line \d+\.)?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let message = captures.get(1).map_or("", |message| message.as_str());
+        let version = Regex::new(r"compat=([0-9.]*[0-9])")
+            .unwrap()
+            .captures(message)
+            .map_or("", |version| version.get(1).unwrap().as_str());
+
+        LogItem::new(
+            LogItemType::PgfplotsCompatibilityMode(version),
+            input_line(message),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for RerunBibliographyTool {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"Package biblatex Warning: Please \(re\)run (\S+) on the file(?:\(s\))?:")
+            .raw(r"\(biblatex\)\s+(\S+)")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::RerunBibliographyTool(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+            ),
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for BibliographyDataEncoding {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"Package biblatex Warning: Data encoding is '([^']+)'\.(")
+            .continuation()
+            .raw(r")")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::BibliographyDataEncoding(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+            ),
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for UndefinedCitation {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"(?:Package natbib|LaTeX) Warning: Citation [`']([^']+)' on page (\d+)")
+            .raw(r"\s+undefined(?: on input line (\d+))?")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::UndefinedCitation(
+                captures.get(1).unwrap().as_str(),
+                usize::from_str(captures.get(2).unwrap().as_str()).unwrap(),
+            ),
+            match captures.get(3) {
+                Some(line) => Location::Line(usize::from_str(line.as_str()).unwrap()),
+                None => Location::None,
+            },
+        )
+    }
+}
+
+impl<'a> Rule<'a> for MultiplyDefinedCitation {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"Package natbib Warning: (?:Citation `([^']+)' multiply defined")
+            .raw(r"|There were multiply defined citations)\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::MultiplyDefinedCitation(captures.get(1).map_or("", |key| key.as_str())),
+            Location::None,
+        )
+    }
+
+    fn process_all(&'a self, captures: Vec<Captures<'a>>) -> Vec<LogItem<'a>> {
+        // the summary is redundant when the keys are known
+        let items = captures
+            .into_iter()
+            .map(|captures| self.process(captures))
+            .collect::<Vec<LogItem<'a>>>();
+
+        if items.len() > 1 {
+            items
+                .into_iter()
+                .filter(|item| item.item_type != LogItemType::MultiplyDefinedCitation(""))
+                .collect()
+        } else {
+            items
+        }
+    }
+}
+
+impl<'a> Rule<'a> for IncompatiblePackage {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"Package (\S+) Error: Incompatible package `?'?([^'`]+)'\.")
+            .context()
+            .location()
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::IncompatiblePackage(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+            ),
+            Location::Line(usize::from_str(captures.get(3).unwrap().as_str()).unwrap()),
+        )
+    }
+}
+
+impl<'a> Rule<'a> for EmptyBibliography {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"LaTeX Warning: Empty `thebibliography' environment(?: on input line (\d+))?\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::EmptyBibliography,
+            match captures.get(1) {
+                Some(line) => Location::Line(usize::from_str(line.as_str()).unwrap()),
+                None => Location::None,
+            },
+        )
+    }
+}
+
+impl<'a> Rule<'a> for LayoutLengthTooSmall {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"Package fancyhdr Warning: (\\\w+) is too small \(([\d.]+pt)\):")
+            .raw(r"\s*(?:\(fancyhdr\))?\s*Make it at least ([\d.]+pt)")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::LayoutLengthTooSmall(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+                captures.get(3).unwrap().as_str(),
+            ),
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for GeometryOverSpecification {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"Package geometry Warning: Over-specification in `(h|v)'-direction\.")
+            .raw(r"\s*`(\w+)' \(([\d.]+pt)\) is ignored\.")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::GeometryOverSpecification(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+                captures.get(3).unwrap().as_str(),
+            ),
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for MicrotypeUnknownSlot {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .line(r"Package microtype Warning: Unknown slot number of character")
+            .line(r"\(microtype\)\s+`(.+)'")
+            .raw(r"\(microtype\)\s+in font encoding `([^']+)'")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        // the location points to the microtype configuration, not to the source
+        LogItem::new(
+            LogItemType::MicrotypeUnknownSlot(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+            ),
+            Location::None,
+        )
+    }
+}
+
+impl<'a> Rule<'a> for BadTypeArea {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"Package typearea Warning: Bad type area settings!(")
+            .continuation()
+            .raw(r")")
+            .into()
+    }
+
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::BadTypeArea(captures.get(1).unwrap().as_str()),
+            Location::None,
+        )
     }
 }
 
-#[derive(PartialEq, Eq, Hash)]
-pub struct LogItem<'a> {
-    pub item_type: LogItemType<'a>,
-    pub location: Location,
-}
-
-impl<'a> LogItem<'a> {
-    fn new(item_type: LogItemType<'a>, location: Location) -> Self {
-        Self {
-            item_type,
-            location,
-        }
+impl<'a> Rule<'a> for MissingHyphenationPatterns {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"Package (?:babel|polyglossia) Warning: No hyphenation patterns were (?:pre)?loaded for")
+            .raw(r"\s+(?:\(babel\)\s+)?(?:the language )?[`']([^']+)'.*")
+            .continuation()
+            .into()
     }
 
-    pub fn rules() -> Vec<&'a Rule<'a>> {
-        vec![
-            &UndefinedControlSequence,
-            &TooManyEndingBraces,
-            &NotInMathMode,
-            &RunawayArgument,
-            &RunawayArgument2,
-            &UnderfullHBox,
-            &OverfullHBox,
-            &MissingPackage,
-            &InvalidOption,
-            &ExtraAlignmentToCR,
-        ]
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        LogItem::new(
+            LogItemType::MissingHyphenationPatterns(captures.get(1).unwrap().as_str()),
+            input_line(captures.get(0).unwrap().as_str()),
+        )
     }
 }
 
-impl<'a> fmt::Display for LogItem<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let level = self.item_type.get_level();
+impl<'a> Rule<'a> for UnknownLanguage {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .raw(r"! Package (?:(babel) Error: Unknown option [`']([^']+)'\. Either you misspelled it")
+            .raw(r"|(polyglossia) Error: (?:File gloss-(\S+)\.ldf does not exist!")
+            .raw(r"|Unknown language [`']([^']+)'))")
+            .into()
+    }
 
-        match self.location {
-            Location::Line(line) => write!(
-                f,
-                "{} {} {}: ",
-                level,
-                Paint::white("on line").italic(),
-                Paint::white(line).bold()
-            )?,
-            Location::End => write!(f, "{} {}: ", level, Paint::white("at the end").italic())?,
-            Location::None => write!(f, "{}: ", level)?,
-        }
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        // the location points to the option processing in the package, not to the source
+        let package = captures.get(1).or(captures.get(3)).unwrap().as_str();
+        let language = captures
+            .get(2)
+            .or(captures.get(4))
+            .or(captures.get(5))
+            .unwrap()
+            .as_str();
 
-        match self.item_type {
-            LogItemType::UndefinedControlSequence(command) => {
-                write!(f, "Unknown command {}.", Paint::cyan(command))
-            }
-            LogItemType::TooManyEndingBraces(context) => write!(
-                f,
-                "Number of curly braces near {} does not match.",
-                Paint::white(context).bold()
-            ),
-            LogItemType::NotInMathMode(input) => write!(
-                f,
-                "String {} is valid only in math mode.",
-                Paint::white(input).bold()
-            ),
-            LogItemType::RunawayArgument(command) => write!(
-                f,
-                "Command {} was not properly ended with curly brace.",
-                Paint::cyan(command),
-            ),
-            LogItemType::UnderfullHBox(input, badness) => if input.is_empty() {
-                write!(
-                    f,
-                    "Line cannot be stretch enough. The problem is {}.",
-                    if badness < 2000 {
-                        "ignorable"
-                    } else if badness < 6000 {
-                        "not as bad"
-                    } else {
-                        "very bad"
-                    }
-                )
-            } else {
-                write!(
-                    f,
-                    "Due to {} the line cannot be stretch enough. The problem is {}.",
-                    input,
-                    if badness < 2000 {
-                        "ignorable"
-                    } else if badness < 6000 {
-                        "not as bad"
-                    } else {
-                        "very bad"
-                    }
-                )
-            },
-            LogItemType::OverfullHBox(input) => write!(
-                f,
-                "Text after {} (displayed hyphenated) overflows the line end.",
-                Paint::white(input.replace("\n", "").trim()).bold()
-            ),
-            LogItemType::MissingPackage(package) => {
-                write!(f, "Missing package {}.", Paint::cyan(package))
-            }
-            LogItemType::InvalidOption(option, package) => write!(
-                f,
-                "Invalid option {} of package {}.",
-                Paint::cyan(option),
-                Paint::white(package).bold()
-            ),
-            LogItemType::ExtraAlignmentToCR(input) => write!(
-                f,
-                "There are more &'s than should be in a aligned environment (table, etc.) near {}.",
-                Paint::white(input).bold()
-            ),
-        }
+        LogItem::new(
+            LogItemType::UnknownLanguage(package, language),
+            Location::None,
+        )
     }
 }
 
-/// Trait for all rules. The task of a rule is specifying the regular expression which is used to
-/// extract information from log file. Then it gets found captures from which the rule creates
-/// corresponding log item. Optionally, the rule can specify custom retrieval of captures from log
-/// file.
-pub trait Rule<'a> {
-    /// Returns regular expression for extraction of information from log file.
-    fn get_regex(&self) -> Regex;
+impl<'a> Rule<'a> for CapacityExceeded {
+    fn get_regex(&self) -> Regex {
+        PatternBuilder::new()
+            .error(r"TeX capacity exceeded, sorry \[([^=\]]+)=(\d+)\]\.")
+            .raw(r"(")
+            .context()
+            .raw(r")")
+            .location()
+            .into()
+    }
 
-    /// Transforms captures to log item.
-    fn process(&'a self, Captures<'a>) -> LogItem<'a>;
+    fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let capacity = captures.get(1).unwrap().as_str();
+        let context = captures.get(3).unwrap().as_str();
 
-    /// Retrieves captures from log file. Custom mechanism can be implemented.
-    fn captures(&'a self, pattern: Regex, log: &'a str) -> Vec<Captures<'a>> {
-        pattern.captures_iter(log).collect()
+        // main memory can be exhausted by a large document, then the trace does not repeat
+        let command = match most_expanded_macro(context) {
+            Some((command, _)) if RECURSION_CAPACITIES.contains(&capacity) => command,
+            Some((command, count)) if count > 1 => command,
+            _ => "",
+        };
+
+        LogItem::new(
+            LogItemType::CapacityExceeded(capacity, captures.get(2).unwrap().as_str(), command),
+            Location::Line(usize::from_str(captures.get(4).unwrap().as_str()).unwrap()),
+        )
     }
 }
 
-// RULES
-
-pub struct UndefinedControlSequence;
-pub struct TooManyEndingBraces;
-pub struct NotInMathMode;
-pub struct RunawayArgument;
-pub struct RunawayArgument2;
-pub struct UnderfullHBox;
-pub struct OverfullHBox;
-pub struct MissingPackage;
-pub struct InvalidOption;
-pub struct ExtraAlignmentToCR;
-
-impl<'a> Rule<'a> for UndefinedControlSequence {
+impl<'a> Rule<'a> for VerbEndedByEndOfLine {
     fn get_regex(&self) -> Regex {
         PatternBuilder::new()
-            .error(r"Undefined control sequence\.")
-            .raw(r"(?:.+\n.+\n)?")
+            .error(r"LaTeX Error: \\verb ended by end of line\.")
+            .context()
             .location()
-            .line(r".*(\\[^{\s]+).*")
             .into()
     }
 
     fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
         LogItem::new(
-            LogItemType::UndefinedControlSequence(captures.get(2).unwrap().as_str()),
+            LogItemType::VerbEndedByEndOfLine,
             Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
         )
     }
 }
 
-impl<'a> Rule<'a> for TooManyEndingBraces {
+impl<'a> Rule<'a> for VerbInArgument {
     fn get_regex(&self) -> Regex {
         PatternBuilder::new()
-            .error(r"Too many }'s\.")
-            .location_with_arg()
+            .error(r"LaTeX Error: \\verb illegal in argument\.")
+            .context()
+            .location()
             .into()
     }
 
     fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
         LogItem::new(
-            LogItemType::TooManyEndingBraces(captures.get(2).unwrap().as_str()),
+            LogItemType::VerbInArgument,
             Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
         )
     }
 }
 
-impl<'a> Rule<'a> for NotInMathMode {
+impl<'a> Rule<'a> for MisplacedAlignment {
     fn get_regex(&self) -> Regex {
         PatternBuilder::new()
-            .error(r"Missing \$ inserted\.")
-            .line("<inserted text> ")
-            .line(r".+\$")
+            .error(r"Misplaced (alignment tab character &|\\noalign|\\omit)\.")
+            .context()
             .location_with_arg()
             .into()
     }
 
     fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
+        let command = match captures.get(1).unwrap().as_str() {
+            "alignment tab character &" => "&",
+            command => command,
+        };
+
         LogItem::new(
-            LogItemType::NotInMathMode(captures.get(2).unwrap().as_str()),
-            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+            LogItemType::MisplacedAlignment(command, captures.get(3).unwrap().as_str().trim()),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
         )
     }
-
-    fn captures(&'a self, pattern: Regex, log: &'a str) -> Vec<Captures<'a>> {
-        pattern.captures_iter(log).step_by(2).collect()
-    }
 }
 
-impl<'a> Rule<'a> for RunawayArgument {
+impl<'a> Rule<'a> for IllegalPreambleToken {
     fn get_regex(&self) -> Regex {
         PatternBuilder::new()
-            .line(r"Runaway argument\?")
-            .any_on_line()
-            .error(r"Paragraph ended before (\S+) was complete\.")
-            .any_on_line()
-            .any_on_line()
-            .location()
+            .error(r"(?:Package array Error:\s+)?Illegal pream-token \((.*)\): `c' used\.")
+            .context()
+            .location_with_arg()
             .into()
     }
 
     fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
         LogItem::new(
-            LogItemType::RunawayArgument(captures.get(1).unwrap().as_str()),
-            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap() - 1),
+            LogItemType::IllegalPreambleToken(
+                captures.get(1).unwrap().as_str(),
+                column_specification(captures.get(3).unwrap().as_str()),
+            ),
+            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
         )
     }
 }
 
-impl<'a> Rule<'a> for RunawayArgument2 {
+impl<'a> Rule<'a> for IllegalArrayCharacter {
     fn get_regex(&self) -> Regex {
         PatternBuilder::new()
-            .line(r"Runaway argument\?")
-            .any_on_line()
-            .error(r"File ended while scanning use of (\S+)\.")
+            .error(r"LaTeX Error: Illegal character in array arg\.")
+            .context()
+            .location_with_arg()
             .into()
     }
 
     fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
         LogItem::new(
-            LogItemType::RunawayArgument(captures.get(1).unwrap().as_str()),
-            Location::End,
+            LogItemType::IllegalPreambleToken(
+                "",
+                column_specification(captures.get(2).unwrap().as_str()),
+            ),
+            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
         )
     }
 }
 
-impl<'a> Rule<'a> for UnderfullHBox {
+impl<'a> Rule<'a> for MissingTitleData {
     fn get_regex(&self) -> Regex {
         PatternBuilder::new()
-            .line(r"Underfull \\hbox \(badness (\d+)\) in paragraph at lines (\d+)--\d+")
-            .line(r"(.*)")
+            .raw(r"(?:LaTeX Warning: No \\(author|title) given\.|")
+            .error(r"LaTeX Error: No \\(author|title) given\.")
+            .context()
+            .location()
+            .raw(r")")
             .into()
     }
 
     fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
         LogItem::new(
-            LogItemType::UnderfullHBox(
-                captures.get(3).unwrap().as_str(),
-                usize::from_str(captures.get(1).unwrap().as_str()).unwrap(),
+            LogItemType::MissingTitleData(
+                captures
+                    .get(1)
+                    .or_else(|| captures.get(2))
+                    .unwrap()
+                    .as_str(),
             ),
-            Location::Line(usize::from_str(captures.get(2).unwrap().as_str()).unwrap()),
+            match captures.get(3) {
+                Some(line) => Location::Line(usize::from_str(line.as_str()).unwrap()),
+                None => Location::None,
+            },
         )
     }
 }
 
-impl<'a> Rule<'a> for OverfullHBox {
+impl<'a> Rule<'a> for RerunForPdfData {
     fn get_regex(&self) -> Regex {
         PatternBuilder::new()
-            .line(r"Overfull \\hbox \([^)]+\) in paragraph at lines (\d+)--\d+")
-            .raw(r"\S+ ((?:.|\s)+)\[\]")
+            .raw(r"Package hyperref Warning: Rerun to get (.+?)\.?\n")
             .into()
     }
 
     fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
         LogItem::new(
-            LogItemType::OverfullHBox(captures.get(2).unwrap().as_str()),
-            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+            LogItemType::RerunForPdfData(captures.get(1).unwrap().as_str()),
+            Location::None,
         )
     }
 }
 
-impl<'a> Rule<'a> for MissingPackage {
+impl<'a> Rule<'a> for UnusedGlobalOptions {
     fn get_regex(&self) -> Regex {
         PatternBuilder::new()
-            .error(r"LaTeX Error: File `([^\.]+)\.sty' not found\.")
+            .raw(r"LaTeX Warning: Unused global option\(s\):\s*\[([^\]]*)\]\.")
             .into()
     }
 
     fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
         LogItem::new(
-            LogItemType::MissingPackage(captures.get(1).unwrap().as_str()),
+            LogItemType::UnusedGlobalOptions(captures.get(1).unwrap().as_str()),
             Location::None,
         )
     }
 }
 
-impl<'a> Rule<'a> for InvalidOption {
+impl<'a> Rule<'a> for FileWritten {
     fn get_regex(&self) -> Regex {
         PatternBuilder::new()
-            .error(r"LaTeX Error: Unknown option `([^']+)' for package `([^']+)'\.")
+            .raw(r"LaTeX Warning: (?:Writing|Overwriting) file `([^']+)'\.")
             .into()
     }
 
     fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
         LogItem::new(
-            LogItemType::InvalidOption(
-                captures.get(1).unwrap().as_str(),
-                captures.get(2).unwrap().as_str(),
-            ),
+            LogItemType::FileWritten(captures.get(1).unwrap().as_str()),
             Location::None,
         )
     }
 }
 
-impl<'a> Rule<'a> for ExtraAlignmentToCR {
+impl<'a> Rule<'a> for ProvidedNameMismatch {
     fn get_regex(&self) -> Regex {
         PatternBuilder::new()
-            .error(r"Extra alignment tab has been changed to \\cr\.")
-            .any_on_line()
-            .any_on_line()
-            .location_with_arg()
+            .raw(r"LaTeX Warning: You have requested (document class|package) `([^']*)',")
+            .raw(r"\s+but the (?:document class|package) provides `([^']*)'\.")
             .into()
     }
 
     fn process(&'a self, captures: Captures<'a>) -> LogItem<'a> {
         LogItem::new(
-            LogItemType::ExtraAlignmentToCR(captures.get(2).unwrap().as_str().trim()),
-            Location::Line(usize::from_str(captures.get(1).unwrap().as_str()).unwrap()),
+            LogItemType::ProvidedNameMismatch(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+                captures.get(3).unwrap().as_str(),
+            ),
+            Location::None,
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a, R: Rule<'a>>(rule: &'a R, log: &'a str) -> Vec<LogItem<'a>> {
+        rule.process_all(rule.captures(rule.get_regex(), log))
+    }
+
+    #[test]
+    fn pdftex_warning_after_page_number() {
+        let log = "[4pdfTeX warning (dest): name{foo} has been referenced but does not exist, repl\n\
+                   aced by a fixed one\n\
+                   ]\n";
+        let log_items = find(&PdfTeXWarning, log);
+
+        assert_eq!(log_items.len(), 1);
+        assert_eq!(
+            log_items[0].item_type,
+            LogItemType::EngineWarning(
+                "pdfTeX",
+                EngineMessageKind::Link,
+                "",
+                "name{foo} has been referenced but does not exist, repl\naced by a fixed one"
+            )
+        );
+    }
+
+    #[test]
+    fn pdftex_warning_does_not_continue_with_next_message() {
+        let log = "pdfTeX warning: pdflatex (file ./fig.pdf): PDF inclusion: found PDF version <1.7>, \
+                   but at most version <1.5> allowed\n\
+                   ! LaTeX Error: No \\title given.\n\
+                   pdfTeX warning (dest): name{bar} has been referenced but does not exist, replac\n\
+                   (./main.aux)\n";
+        let log_items = find(&PdfTeXWarning, log);
+
+        assert_eq!(log_items.len(), 2);
+        assert_eq!(
+            log_items[0].item_type,
+            LogItemType::EngineWarning(
+                "pdfTeX",
+                EngineMessageKind::Inclusion,
+                "./fig.pdf",
+                "PDF inclusion: found PDF version <1.7>, but at most version <1.5> allowed"
+            )
+        );
+        assert_eq!(
+            log_items[1].item_type,
+            LogItemType::EngineWarning(
+                "pdfTeX",
+                EngineMessageKind::Link,
+                "",
+                "name{bar} has been referenced but does not exist, replac"
+            )
+        );
+    }
+
+    #[test]
+    fn wrapped_pdftex_error() {
+        let log = "! pdfTeX error (ext1): \\pdfendlink ended up in different nesting level than \\pd\n\
+                   fstartlink.\n\
+                   <to be read again> \n                   \\endgroup \n\
+                   l.30 \\end{itemize}\n";
+        let log_items = find(&PdfTeXError, log);
+
+        assert_eq!(log_items.len(), 1);
+        assert_eq!(
+            log_items[0].item_type,
+            LogItemType::EngineError(
+                "pdfTeX",
+                EngineMessageKind::Link,
+                "",
+                "\\pdfendlink ended up in different nesting level than \\pd\nfstartlink."
+            )
+        );
+        assert_eq!(log_items[0].location, Location::Line(30));
+    }
+
+    #[test]
+    fn lua_error_with_traceback() {
+        let log = "! LuaTeX error [string \"\\directlua \"]:1: attempt to call a nil value \
+                   (global 'foo')\n\
+                   stack traceback:\n\
+                   \t[C]: in global 'foo'\n\
+                   \t[string \"\\directlua \"]:1: in main chunk.\n\
+                   l.4 \\directlua{foo()}\n";
+        let log_items = find(&LuaError, log);
+
+        assert_eq!(log_items.len(), 1);
+        assert_eq!(
+            log_items[0].item_type,
+            LogItemType::LuaError(
+                "\\directlua",
+                1,
+                "attempt to call a nil value (global 'foo')",
+                vec!["[C]: in global 'foo'", "[string \"\\directlua \"]:1: in main chunk"]
+            )
+        );
+        assert_eq!(log_items[0].location, Location::Line(4));
+    }
+
+    #[test]
+    fn lua_error_of_newer_luatex() {
+        let log = "[\\directlua]:1: attempt to perform arithmetic on a nil value\n\
+                   stack traceback:\n\
+                   \t[\\directlua]:1: in main chunk.\n\
+                   l.7 \\directlua{tex.print(x + 1)}\n";
+        let log_items = find(&LuaError, log);
+
+        assert_eq!(log_items.len(), 1);
+        assert_eq!(
+            log_items[0].item_type,
+            LogItemType::LuaError(
+                "\\directlua",
+                1,
+                "attempt to perform arithmetic on a nil value",
+                vec!["[\\directlua]:1: in main chunk"]
+            )
+        );
+        assert_eq!(log_items[0].location, Location::Line(7));
+    }
+
+    #[test]
+    fn lua_error_in_file() {
+        let log = "./script.lua:3: attempt to index a nil value (local 'node')\n\
+                   \n\
+                   LaTeX Warning: Label(s) may have changed. Rerun to get cross-references right.\n";
+        let log_items = find(&LuaError, log);
+
+        assert_eq!(log_items.len(), 1);
+        assert_eq!(
+            log_items[0].item_type,
+            LogItemType::LuaError(
+                "./script.lua",
+                3,
+                "attempt to index a nil value (local 'node')",
+                Vec::new()
+            )
+        );
+        assert_eq!(log_items[0].location, Location::None);
+    }
+    #[test]
+    fn expl3_error_with_help() {
+        let log = "! LaTeX3 Error: Variable \\l_foo_tl undefined.\n\
+                   \n\
+                   For immediate help type H <return>.\n \
+                   ...                                              \n\
+                   \n\
+                   l.5 \\tl_use:N \\l_foo_tl\n\
+                   \n\
+                   |'''''''''''''''''''''''''''''''''''''''''''''''\n\
+                   | This is a coding error.\n\
+                   | \n\
+                   | LaTeX has been asked to use a variable \\l_foo_tl, but this has not been\n\
+                   | defined.\n\
+                   |...............................................\n";
+        let log_items = find(&Expl3Error, log);
+
+        assert_eq!(log_items.len(), 1);
+        assert_eq!(log_items[0].location, Location::Line(5));
+
+        match log_items[0].item_type {
+            LogItemType::Expl3Error(source, message, help) => {
+                assert_eq!(source, "LaTeX3");
+                assert_eq!(message, "Variable \\l_foo_tl undefined.");
+                assert_eq!(
+                    join_message_lines(help, "|"),
+                    "This is a coding error. LaTeX has been asked to use a variable \\l_foo_tl, \
+                     but this has not been defined."
+                );
+            }
+            _ => panic!("Expected expl3 error"),
+        }
+    }
+
+    #[test]
+    fn expl3_package_error_with_continuation() {
+        let log = "! Package siunitx Error: Invalid number '1,2,3'.\n\
+                   (siunitx)                Only one decimal marker is allowed.\n\
+                   \n\
+                   For immediate help type H <return>.\n\
+                   l.6 \\num{1,2,3}\n\
+                   \n";
+        let log_items = find(&Expl3Error, log);
+
+        assert_eq!(log_items.len(), 1);
+        assert_eq!(log_items[0].location, Location::Line(6));
+
+        match log_items[0].item_type {
+            LogItemType::Expl3Error(source, message, help) => {
+                assert_eq!(source, "siunitx");
+                assert_eq!(
+                    join_message_lines(message, "("),
+                    "Invalid number '1,2,3'. Only one decimal marker is allowed."
+                );
+                assert_eq!(help, "");
+            }
+            _ => panic!("Expected expl3 error"),
+        }
+    }
+    #[test]
+    fn wrapped_pgfplots_error() {
+        let log = "! Package pgfplots Error: Sorry, you can't use 'y' in this context. PGFPlots ex\n\
+                   pected to sample a line, not a mesh. Please use the [mesh] option combined with\n \
+                   [samples y>0] and [domain y!=0:0] to indicate a twodimensional input domain.\n\
+                   \n\
+                   See the pgfplots package documentation for explanation.\n\
+                   Type  H <return>  for immediate help.\n \
+                   ...                                              \n\
+                   \n\
+                   l.8 \\end{axis}\n";
+        let log_items = find(&PgfplotsError, log);
+
+        assert_eq!(log_items.len(), 1);
+        assert_eq!(
+            log_items[0].item_type,
+            LogItemType::PgfplotsError(
+                "Sorry, you can't use 'y' in this context. PGFPlots ex\n\
+                 pected to sample a line, not a mesh. Please use the [mesh] option combined with\n \
+                 [samples y>0] and [domain y!=0:0] to indicate a twodimensional input domain."
+            )
+        );
+        assert_eq!(log_items[0].location, Location::Line(8));
+    }
+
+    #[test]
+    fn pgfplots_error_does_not_continue_with_next_message() {
+        let log = "! Package pgfplots Error: The requested list entry with label '3' is too large\n\
+                   ! Package pgfplots Error: Could not read table file 'data.dat'.\n";
+        let log_items = find(&PgfplotsError, log);
+
+        assert_eq!(log_items.len(), 2);
+        assert_eq!(
+            log_items[0].item_type,
+            LogItemType::PgfplotsError("The requested list entry with label '3' is too large")
+        );
+        assert_eq!(
+            log_items[1].item_type,
+            LogItemType::PgfplotsError("Could not read table file 'data.dat'.")
+        );
+    }
+    #[test]
+    fn language_packages_of_babel_names() {
+        assert_eq!(
+            language_packages("ngerman"),
+            (String::from("babel-german"), String::from("hyphen-german"))
+        );
+        assert_eq!(
+            language_packages("Brazil"),
+            (String::from("babel-portuges"), String::from("hyphen-portuguese"))
+        );
+        assert_eq!(
+            language_packages("klingon"),
+            (String::from("babel-klingon"), String::from("hyphen-klingon"))
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::fs::File;
+use std::io::Read;
+
+use rules::{resolve_rule, LogItem, LogItemTypeLevel};
+
+/// Name of the configuration file which is loaded from the current directory.
+pub const CONFIG_FILE: &str = ".latexerr";
+
+/// Severities of rules configured by the user, which override the default levels. A rule is
+/// configured either by its identifier (`LX0006`), its full name (`overfull-hbox`) or by a prefix of
+/// words in its name (`overfull`) which refers to only one rule. Later entries take precedence over
+/// earlier ones.
+pub struct Severities {
+    entries: Vec<(&'static str, Option<LogItemTypeLevel>)>,
+}
+
+impl Severities {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Loads severities from a configuration file, which contains one `rule = severity` entry per
+    /// line. Empty lines and lines starting with `#` are ignored. Missing file is not an error.
+    pub fn load(&mut self, filename: &str) -> Result<(), String> {
+        let mut content = String::new();
+
+        match File::open(filename) {
+            Ok(mut file) => file
+                .read_to_string(&mut content)
+                .map_err(|_| format!("Cannot read {}", filename))?,
+            Err(_) => return Ok(()),
+        };
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if !line.is_empty() && !line.starts_with('#') {
+                self.add(line)
+                    .map_err(|error| format!("{} (line {} of {})", error, index + 1, filename))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds a `rule=severity` entry, where severity is one of `error`, `warning`, `info` and `off`.
+    pub fn add(&mut self, entry: &str) -> Result<(), String> {
+        let mut parts = entry.splitn(2, '=');
+        let rule = parts.next().unwrap().trim();
+        let severity = parts.next().map(|severity| severity.trim());
+
+        let level = match severity {
+            Some("error") => Some(LogItemTypeLevel::Error),
+            Some("warning") => Some(LogItemTypeLevel::Warning),
+            Some("info") => Some(LogItemTypeLevel::Info),
+            Some("off") => None,
+            _ => return Err(format!("Invalid severity entry {}", entry)),
+        };
+
+        if rule.is_empty() {
+            return Err(format!("Missing rule name in severity entry {}", entry));
+        }
+
+        self.entries.push((resolve_rule(rule)?.id, level));
+        Ok(())
+    }
+
+    /// Sets levels of log items according to configured severities and removes items of rules
+    /// which are turned off.
+    pub fn apply<'a>(&self, log_items: Vec<LogItem<'a>>) -> Vec<LogItem<'a>> {
+        log_items
+            .into_iter()
+            .filter_map(|mut log_item| {
+                let id = log_item.item_type.get_id();
+
+                match self.entries.iter().rev().find(|&&(rule, _)| rule == id) {
+                    Some((_, Some(level))) => {
+                        log_item.level = *level;
+                        Some(log_item)
+                    }
+                    Some((_, None)) => None,
+                    None => Some(log_item),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::fs::File;
+    use std::io::Write;
+
+    use rules::{Location, LogItemType};
+
+    fn log_items() -> Vec<LogItem<'static>> {
+        vec![
+            LogItem {
+                item_type: LogItemType::OverfullHBox("10.0pt"),
+                location: Location::Line(4),
+                level: LogItemTypeLevel::Warning,
+            },
+            LogItem {
+                item_type: LogItemType::UndefinedControlSequence("\\foo"),
+                location: Location::Line(6),
+                level: LogItemTypeLevel::Error,
+            },
+        ]
+    }
+
+    fn levels(log_items: Vec<LogItem>) -> Vec<LogItemTypeLevel> {
+        log_items
+            .into_iter()
+            .map(|log_item| log_item.level)
+            .collect()
+    }
+
+    #[test]
+    fn add_valid_entries() {
+        let mut severities = Severities::new();
+
+        assert!(severities.add("LX0006=error").is_ok());
+        assert!(severities.add("lx0006 = error").is_ok());
+        assert!(severities.add("overfull-hbox=warning").is_ok());
+        assert!(severities.add("overfull = info").is_ok());
+        assert!(severities.add("undefined-control-sequence=off").is_ok());
+    }
+
+    #[test]
+    fn add_invalid_entries() {
+        let mut severities = Severities::new();
+
+        assert_eq!(
+            severities.add("overfull=fatal"),
+            Err(String::from("Invalid severity entry overfull=fatal"))
+        );
+        assert_eq!(
+            severities.add("overfull"),
+            Err(String::from("Invalid severity entry overfull"))
+        );
+        assert_eq!(
+            severities.add("=error"),
+            Err(String::from("Missing rule name in severity entry =error"))
+        );
+        assert_eq!(
+            severities.add("overful=error"),
+            Err(String::from("Unknown rule overful"))
+        );
+        assert_eq!(
+            severities.add("LX9999=error"),
+            Err(String::from("Unknown rule LX9999"))
+        );
+        assert!(severities
+            .add("missing=off")
+            .unwrap_err()
+            .starts_with("Rule missing is ambiguous, it can be any of missing-"));
+    }
+
+    #[test]
+    fn apply_overrides_levels() {
+        let mut severities = Severities::new();
+        severities.add("overfull=error").unwrap();
+        assert_eq!(
+            levels(severities.apply(log_items())),
+            vec![LogItemTypeLevel::Error, LogItemTypeLevel::Error]
+        );
+
+        // later entries take precedence
+        severities.add("LX0006=info").unwrap();
+        assert_eq!(
+            levels(severities.apply(log_items())),
+            vec![LogItemTypeLevel::Info, LogItemTypeLevel::Error]
+        );
+
+        severities.add("undefined-control-sequence=off").unwrap();
+        assert_eq!(
+            levels(severities.apply(log_items())),
+            vec![LogItemTypeLevel::Info]
+        );
+    }
+
+    #[test]
+    fn command_line_takes_precedence_over_config_file() {
+        let filename = env::temp_dir().join("latexerr-severity-test");
+        let filename = filename.to_str().unwrap();
+
+        File::create(filename)
+            .and_then(|mut file| {
+                file.write_all(b"# comment\n\noverfull-hbox = error\nLX0001 = warning\n")
+            })
+            .unwrap();
+
+        // the same order as in main
+        let mut severities = Severities::new();
+        severities.load(filename).unwrap();
+        severities.add("overfull=info").unwrap();
+
+        assert_eq!(
+            levels(severities.apply(log_items())),
+            vec![LogItemTypeLevel::Info, LogItemTypeLevel::Warning]
+        );
+    }
+
+    #[test]
+    fn config_file_errors_have_line_numbers() {
+        let filename = env::temp_dir().join("latexerr-severity-invalid-test");
+        let filename = filename.to_str().unwrap();
+
+        File::create(filename)
+            .and_then(|mut file| file.write_all(b"overfull-hbox = error\nfoo = error\n"))
+            .unwrap();
+
+        let mut severities = Severities::new();
+        assert_eq!(
+            severities.load(filename),
+            Err(format!("Unknown rule foo (line 2 of {})", filename))
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use regex::Regex;
+
+use rules::{resolve_rule, Location, LogItem, LogItemType};
+
+/// Rules which are disabled at some place of a source file, referred to by their identifiers.
+#[derive(Clone)]
+struct Disabled {
+    all: bool,
+    rules: Vec<&'static str>,
+    enabled: Vec<&'static str>,
+}
+
+impl Disabled {
+    fn none() -> Self {
+        Self {
+            all: false,
+            rules: Vec::new(),
+            enabled: Vec::new(),
+        }
+    }
+
+    /// Disables given rules, or all rules if none is given.
+    fn disable(&mut self, rules: &[&'static str]) {
+        if rules.is_empty() {
+            *self = Self::none();
+            self.all = true;
+        } else {
+            self.rules.extend_from_slice(rules);
+            self.enabled.retain(|rule| !rules.contains(rule));
+        }
+    }
+
+    /// Enables given rules, or all rules if none is given. When all rules are disabled, the given
+    /// ones are kept as exceptions.
+    fn enable(&mut self, rules: &[&'static str]) {
+        if rules.is_empty() {
+            *self = Self::none();
+        } else {
+            self.rules.retain(|rule| !rules.contains(rule));
+
+            if self.all {
+                self.enabled.extend_from_slice(rules);
+            }
+        }
+    }
+
+    fn covers(&self, item_type: &LogItemType) -> bool {
+        let id = item_type.get_id();
+
+        (self.all && !self.enabled.contains(&id)) || self.rules.contains(&id)
+    }
+}
+
+/// Suppressions of log items written as comments in a source file:
+///
+/// * `% latexerr-disable-next-line rule` disables the rule on the next line,
+/// * `% latexerr-disable rule` disables the rule until `% latexerr-enable rule` or the end of the
+///   file (`% latexerr-enable` without rules enables all of them),
+/// * `% latexerr-disable-file rule` disables the rule in the whole file.
+///
+/// Rules are referred to the same way as in severity configuration. When no rule is given, all
+/// rules are disabled. Unknown rules are reported and ignored.
+pub struct Suppressions {
+    file: Disabled,
+    lines: Vec<Disabled>,
+}
+
+impl Suppressions {
+    /// Reads suppressions from a source file. If the file cannot be read, nothing is suppressed.
+    pub fn load(path: &Path) -> Self {
+        let mut content = Vec::new();
+
+        if let Ok(mut file) = File::open(path) {
+            if file.read_to_end(&mut content).is_err() {
+                content.clear();
+            }
+        }
+
+        let (suppressions, errors) = Self::parse(&String::from_utf8_lossy(&content));
+        for error in errors {
+            eprintln!("{} in {}", error, path.display());
+        }
+
+        suppressions
+    }
+
+    /// Parses suppression comments, returns also errors of unknown rules.
+    fn parse(source: &str) -> (Self, Vec<String>) {
+        let pattern = Regex::new(
+            r"(?:^|[^\\])%\s*latexerr-(disable-next-line|disable-file|disable|enable)\b(.*)",
+        ).unwrap();
+
+        let mut file = Disabled::none();
+        let mut lines = Vec::new();
+        let mut current = Disabled::none();
+        let mut next_line: Option<Vec<&'static str>> = None;
+        let mut errors = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let mut disabled = current.clone();
+            if let Some(rules) = next_line.take() {
+                disabled.disable(&rules);
+            }
+            lines.push(disabled);
+
+            if let Some(captures) = pattern.captures(line) {
+                let names = captures
+                    .get(2)
+                    .unwrap()
+                    .as_str()
+                    .split(|character: char| character.is_whitespace() || character == ',')
+                    .filter(|name| !name.is_empty())
+                    .collect::<Vec<&str>>();
+
+                let rules = names
+                    .iter()
+                    .filter_map(|name| match resolve_rule(name) {
+                        Ok(info) => Some(info.id),
+                        Err(error) => {
+                            errors.push(format!("{} on line {}", error, index + 1));
+                            None
+                        }
+                    })
+                    .collect::<Vec<&'static str>>();
+
+                // the comment must not affect all rules when none of the given ones is known
+                if rules.is_empty() && !names.is_empty() {
+                    continue;
+                }
+
+                match captures.get(1).unwrap().as_str() {
+                    "disable-next-line" => next_line = Some(rules),
+                    "disable-file" => file.disable(&rules),
+                    "disable" => current.disable(&rules),
+                    _ => current.enable(&rules),
+                }
+            }
+        }
+
+        (Self { file, lines }, errors)
+    }
+
+    /// Removes log items which are suppressed in the source file.
+    pub fn apply<'a>(&self, log_items: Vec<LogItem<'a>>) -> Vec<LogItem<'a>> {
+        log_items
+            .into_iter()
+            .filter(|log_item| !self.is_suppressed(log_item))
+            .collect()
+    }
+
+    fn is_suppressed(&self, log_item: &LogItem) -> bool {
+        if self.file.covers(&log_item.item_type) {
+            return true;
+        }
+
+        match log_item.location {
+            Location::Line(line) if line > 0 => self
+                .lines
+                .get(line - 1)
+                .is_some_and(|disabled| disabled.covers(&log_item.item_type)),
+            _ => false,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use regex::Regex;
+use regex::{Captures, Regex};
 
 pub struct PatternBuilder {
     pattern: String,
@@ -29,6 +29,26 @@ impl PatternBuilder {
         self.line(".*")
     }
 
+    /// Skips context lines (such as `<inserted text>` or `<to be read again>`) which TeX prints
+    /// between the error message and the location, but never goes past the next error. Emergency
+    /// stop is not considered as the next error since it reports the same location.
+    pub fn context(self) -> Self {
+        self.raw(r"(?:(?:[^!\n].*|! Emergency stop\.)?\n)*?")
+    }
+
+    /// Skips at most a few context lines which TeX prints right after a message (such as
+    /// `<to be read again>` with the token below it). Unlike `context`, it does not search further
+    /// in the log, so it is suitable for an optional location after a warning.
+    pub fn short_context(self) -> Self {
+        self.raw(r"(?:(?:<[^>\n]*>.*|[ \t]+.*|\\\S+ .*->.*)?\n){0,4}")
+    }
+
+    /// Matches continuation lines of a LaTeX or package message, which are prefixed with the name
+    /// of the package in parentheses, for example `(hyperref)`.
+    pub fn continuation(self) -> Self {
+        self.raw(r"(?:\n\([^)\n]+\) .*)*")
+    }
+
     pub fn location(self) -> Self {
         self.raw(r"l\.(\d+) ")
     }
@@ -43,3 +63,119 @@ impl Into<Regex> for PatternBuilder {
         Regex::new(&self.pattern).unwrap()
     }
 }
+
+/// Number of characters after which TeX breaks lines in the log (`max_print_line`).
+const MAX_PRINT_LINE: usize = 79;
+
+/// Beginnings of lines which start a new message, so they never continue a broken line.
+const MESSAGE_PREFIXES: &[&str] = &[
+    "!",
+    "(",
+    "[",
+    "l.",
+    "<*>",
+    "<to be read again>",
+    "<inserted text>",
+    "<recently read>",
+    "<argument>",
+    "LaTeX ",
+    "Package ",
+    "Class ",
+    "pdfTeX ",
+    "Overfull ",
+    "Underfull ",
+    "Output written",
+    "Transcript written",
+];
+
+/// Finds messages which TeX breaks into more lines when they are too long. The pattern matches the
+/// beginning of a message in its first group. The message continues on the next line only if the
+/// physical line is full and the next line does not start another message. The returned captures
+/// contain the whole message in the first group, followed by groups of `rest` pattern which must
+/// match right after the message.
+pub fn wrapped_captures<'a>(
+    pattern: &Regex,
+    rest: PatternBuilder,
+    log: &'a str,
+) -> Vec<Captures<'a>> {
+    let mut output = Vec::new();
+    let mut last = 0;
+
+    for start in pattern
+        .captures_iter(log)
+        .map(|captures| captures.get(1).unwrap().start())
+    {
+        // the beginning was already taken as a continuation of the previous message
+        if start < last {
+            continue;
+        }
+
+        let end = wrapped_message_end(log, start);
+        let pattern = Regex::new(&format!(
+            r"\A((?s:.{{{}}})){}",
+            log[start..end].chars().count(),
+            rest.pattern
+        ))
+        .unwrap();
+
+        if let Some(captures) = pattern.captures(&log[start..]) {
+            last = end;
+            output.push(captures);
+        }
+    }
+
+    output
+}
+
+fn wrapped_message_end(log: &str, start: usize) -> usize {
+    let line_end = |from: usize| {
+        log[from..]
+            .find('\n')
+            .map_or(log.len(), |index| from + index)
+    };
+
+    let mut line_start = log[..start].rfind('\n').map_or(0, |index| index + 1);
+    let mut end = line_end(start);
+
+    // pdfTeX counts bytes, XeTeX and LuaTeX count characters
+    while end < log.len() {
+        let line = &log[line_start..end];
+        if line.len() != MAX_PRINT_LINE && line.chars().count() != MAX_PRINT_LINE {
+            break;
+        }
+
+        let next = &log[end + 1..line_end(end + 1)];
+        if next.trim().is_empty()
+            || MESSAGE_PREFIXES
+                .iter()
+                .any(|prefix| next.starts_with(prefix))
+        {
+            break;
+        }
+
+        line_start = end + 1;
+        end = line_end(line_start);
+    }
+
+    end
+}
+
+/// Computes the edit distance between two strings, which is the number of insertions, deletions
+/// and substitutions needed to transform one string into the other.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<char>>();
+    let mut previous = (0..b.len() + 1).collect::<Vec<usize>>();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == *b_char { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}